@@ -0,0 +1,555 @@
+//! Clock-like decomposition of durations into days, hours, minutes and seconds.
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use duration::{FloatDuration, NANOS_PER_SEC, SECS_PER_YEAR, SECS_PER_WEEK, SECS_PER_DAY,
+               SECS_PER_HOUR, SECS_PER_MINUTE};
+use error::ParseDurationError;
+
+/// A duration broken down into whole days, hours, minutes and seconds.
+///
+/// Unlike `FloatDuration`, which stores a single fractional number of seconds,
+/// `DecomposedTime` stores each component separately, which is mainly useful for
+/// displaying a duration in a clock-like `HH:MM:SS` form. A `DecomposedTime` is
+/// created from a `FloatDuration` with `FloatDuration::decompose`, and the two
+/// may be converted back-and-forth at will via `to_float_duration`.
+///
+/// All components are stored as magnitudes; the direction of the duration is
+/// held separately in `sign`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecomposedTime {
+    /// The number of whole days.
+    pub days: u64,
+    /// The number of whole hours.
+    pub hours: u32,
+    /// The number of whole minutes.
+    pub minutes: u32,
+    /// The number of whole seconds.
+    pub seconds: u32,
+    /// The fractional part of a second, in the range `[0, 1)`.
+    pub fractional_seconds: f64,
+    /// `1` for a forward duration and `-1` for a backward duration.
+    pub sign: i32,
+}
+
+impl DecomposedTime {
+    /// Create a new, positive `DecomposedTime` from its components.
+    pub fn from_components(days: u64,
+                           hours: u32,
+                           minutes: u32,
+                           seconds: u32,
+                           fractional_seconds: f64)
+                           -> DecomposedTime {
+        DecomposedTime {
+            days,
+            hours,
+            minutes,
+            seconds,
+            fractional_seconds,
+            sign: 1,
+        }
+    }
+    /// Create a new, positive `DecomposedTime` from its components, with the
+    /// fraction of a second given as a whole number of milliseconds.
+    ///
+    /// # Panics
+    /// This function panics if `millis` is not less than `1000`.
+    pub fn from_components_milli(days: u64,
+                                 hours: u32,
+                                 minutes: u32,
+                                 seconds: u32,
+                                 millis: u32)
+                                 -> DecomposedTime {
+        assert!(millis < 1000, "millis must be less than 1000");
+        DecomposedTime::from_components(days, hours, minutes, seconds, millis as f64 / 1000.0)
+    }
+    /// Return a new `DecomposedTime` that represents zero elapsed time.
+    pub fn zero() -> DecomposedTime {
+        DecomposedTime::from_components(0, 0, 0, 0, 0.0)
+    }
+
+    /// Return the fractional part of a second as a whole number of milliseconds.
+    ///
+    /// The result is truncated from `nanoseconds`, so it lies in `[0, 999]`.
+    #[inline]
+    pub fn milliseconds(&self) -> u32 {
+        self.nanoseconds() / 1_000_000
+    }
+    /// Return the fractional part of a second as a whole number of microseconds.
+    ///
+    /// The result is truncated from `nanoseconds`, so it lies in `[0, 999_999]`.
+    #[inline]
+    pub fn microseconds(&self) -> u32 {
+        self.nanoseconds() / 1_000
+    }
+    /// Return the fractional part of a second as a whole number of nanoseconds.
+    ///
+    /// The fraction is rounded to the nearest nanosecond, but never rounds up to a
+    /// full second: values within half a nanosecond of `1.0` give `999_999_999`.
+    pub fn nanoseconds(&self) -> u32 {
+        let nanos = (self.fractional_seconds * NANOS_PER_SEC).round();
+        if nanos <= 0.0 {
+            0
+        } else if nanos >= NANOS_PER_SEC {
+            999_999_999
+        } else {
+            nanos as u32
+        }
+    }
+
+    /// Carry out-of-range components into the next larger unit.
+    ///
+    /// Whole seconds in `fractional_seconds` are carried into `seconds`, and any
+    /// seconds, minutes or hours beyond their clock limits are carried into minutes,
+    /// hours and days respectively. The total duration is unchanged, and
+    /// normalizing an already normalized value returns it unchanged.
+    pub fn normalize(self) -> DecomposedTime {
+        let mut fractional_seconds = self.fractional_seconds;
+        let mut seconds = self.seconds as u64;
+        if fractional_seconds >= 1.0 {
+            seconds += fractional_seconds.trunc() as u64;
+            fractional_seconds = fractional_seconds.fract();
+        }
+        let minutes = self.minutes as u64 + seconds / 60;
+        let hours = self.hours as u64 + minutes / 60;
+
+        DecomposedTime {
+            days: self.days + hours / 24,
+            hours: (hours % 24) as u32,
+            minutes: (minutes % 60) as u32,
+            seconds: (seconds % 60) as u32,
+            fractional_seconds,
+            sign: self.sign,
+        }
+    }
+
+    /// Convert this `DecomposedTime` into a `FloatDuration`.
+    ///
+    /// The components are summed and then multiplied by `sign`.
+    pub fn to_float_duration(&self) -> FloatDuration {
+        let secs = self.days as f64 * SECS_PER_DAY + self.hours as f64 * SECS_PER_HOUR +
+                   self.minutes as f64 * SECS_PER_MINUTE +
+                   self.seconds as f64 + self.fractional_seconds;
+        FloatDuration::seconds(secs * self.sign as f64)
+    }
+}
+
+impl Default for DecomposedTime {
+    #[inline]
+    fn default() -> DecomposedTime {
+        DecomposedTime::zero()
+    }
+}
+
+impl From<DecomposedTime> for FloatDuration {
+    #[inline]
+    fn from(from: DecomposedTime) -> FloatDuration {
+        from.to_float_duration()
+    }
+}
+
+impl ops::Add<DecomposedTime> for DecomposedTime {
+    type Output = DecomposedTime;
+
+    /// Add the two times as `FloatDuration`s and decompose the result.
+    #[inline]
+    fn add(self, rhs: DecomposedTime) -> DecomposedTime {
+        (self.to_float_duration() + rhs.to_float_duration()).decompose()
+    }
+}
+impl ops::Sub<DecomposedTime> for DecomposedTime {
+    type Output = DecomposedTime;
+
+    /// Subtract the two times as `FloatDuration`s and decompose the result.
+    #[inline]
+    fn sub(self, rhs: DecomposedTime) -> DecomposedTime {
+        (self.to_float_duration() - rhs.to_float_duration()).decompose()
+    }
+}
+
+impl fmt::Display for DecomposedTime {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        // Components beyond their clock limits, including whole seconds held in
+        // `fractional_seconds`, are carried so that each field prints within range.
+        let time = self.normalize();
+        if time.sign < 0 {
+            write!(fmt, "-")?;
+        }
+        if time.days > 0 {
+            write!(fmt, "{}d ", time.days)?;
+        }
+        write!(fmt, "{:02}:{:02}:{:02}", time.hours, time.minutes, time.seconds)?;
+        if time.fractional_seconds > 0.0 {
+            // Print the fraction without its leading zero, e.g. ".25".
+            let fraction = format!("{}", time.fractional_seconds);
+            write!(fmt, "{}", &fraction[1..])?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a `DecomposedTime` from the format produced by its `Display` implementation.
+///
+/// The accepted format is an optional leading `-`, an optional `"<days>d "` prefix,
+/// and a clock time `HH:MM:SS` with an optional fractional part such as `".25"`.
+/// Components are not required to be within clock limits.
+impl FromStr for DecomposedTime {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<DecomposedTime, ParseDurationError> {
+        let (sign, rest) = if let Some(rest) = s.strip_prefix('-') {
+            (-1, rest)
+        } else {
+            (1, s)
+        };
+        let (days, clock) = match rest.find("d ") {
+            Some(idx) => (parse_component(&rest[..idx])?, &rest[idx + 2..]),
+            None => (0, rest),
+        };
+        let (whole, fraction) = match clock.find('.') {
+            Some(idx) => clock.split_at(idx),
+            None => (clock, ""),
+        };
+
+        let mut parts = whole.split(':');
+        let hours = parse_component(parts.next().unwrap_or(""))?;
+        let minutes = parse_component(parts.next().unwrap_or(""))?;
+        let seconds = parse_component(parts.next().unwrap_or(""))?;
+        if parts.next().is_some() {
+            return Err(ParseDurationError::new());
+        }
+
+        let fractional_seconds = if fraction.is_empty() {
+            0.0
+        } else {
+            // `fraction` includes its leading '.', which must be followed by digits.
+            parse_component::<u64>(&fraction[1..])?;
+            format!("0{}", fraction).parse().map_err(|_| ParseDurationError::new())?
+        };
+
+        Ok(DecomposedTime {
+            days,
+            hours,
+            minutes,
+            seconds,
+            fractional_seconds,
+            sign,
+        })
+    }
+}
+
+/// A duration broken down into whole years, weeks, days, hours, minutes and
+/// seconds.
+///
+/// This is the counterpart of `DecomposedTime` for very long durations, created
+/// with `FloatDuration::decompose_long`. Whole days are carried into years of
+/// exactly 365 days first, and the remaining days into weeks of 7 days, so a
+/// normalized value has at most 52 weeks and 6 days. As with `DecomposedTime`,
+/// all components are magnitudes and the direction is held in `sign`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecomposedTimeLong {
+    /// The number of whole 365-day years.
+    pub years: u64,
+    /// The number of whole weeks.
+    pub weeks: u32,
+    /// The number of whole days.
+    pub days: u32,
+    /// The number of whole hours.
+    pub hours: u32,
+    /// The number of whole minutes.
+    pub minutes: u32,
+    /// The number of whole seconds.
+    pub seconds: u32,
+    /// The fractional part of a second, in the range `[0, 1)`.
+    pub fractional_seconds: f64,
+    /// `1` for a forward duration and `-1` for a backward duration.
+    pub sign: i32,
+}
+
+impl DecomposedTimeLong {
+    /// Convert this `DecomposedTimeLong` into a `FloatDuration`.
+    ///
+    /// The components are summed and then multiplied by `sign`.
+    pub fn to_float_duration(&self) -> FloatDuration {
+        let secs = self.years as f64 * SECS_PER_YEAR + self.weeks as f64 * SECS_PER_WEEK +
+                   self.days as f64 * SECS_PER_DAY + self.hours as f64 * SECS_PER_HOUR +
+                   self.minutes as f64 * SECS_PER_MINUTE +
+                   self.seconds as f64 + self.fractional_seconds;
+        FloatDuration::seconds(secs * self.sign as f64)
+    }
+}
+
+impl From<DecomposedTimeLong> for FloatDuration {
+    #[inline]
+    fn from(from: DecomposedTimeLong) -> FloatDuration {
+        from.to_float_duration()
+    }
+}
+
+fn parse_component<T: FromStr>(s: &str) -> Result<T, ParseDurationError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseDurationError::new());
+    }
+    s.parse().map_err(|_| ParseDurationError::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_float_duration() {
+        assert_eq!(DecomposedTime::zero().to_float_duration(), FloatDuration::zero());
+        assert_eq!(DecomposedTime::zero().to_float_duration().as_seconds(), 0.0);
+
+        let time1 = DecomposedTime::from_components(1, 2, 3, 4, 0.5);
+        assert_eq!(time1.to_float_duration().as_seconds(),
+                   SECS_PER_DAY + 2.0 * SECS_PER_HOUR + 3.0 * SECS_PER_MINUTE + 4.5);
+        assert_eq!(FloatDuration::from(time1), time1.to_float_duration());
+
+        let time2 = DecomposedTime::from_components(0, 0, 1, 30, 0.0);
+        assert_eq!(time2.to_float_duration(), FloatDuration::seconds(90.0));
+
+        let time3 = DecomposedTime { sign: -1, ..time2 };
+        assert_eq!(time3.to_float_duration().as_seconds(), -90.0);
+        assert!(time3.to_float_duration().is_negative());
+    }
+
+    #[test]
+    fn test_decompose() {
+        assert_eq!(FloatDuration::zero().decompose(), DecomposedTime::zero());
+        assert_eq!((-FloatDuration::zero()).decompose(), DecomposedTime::zero());
+
+        assert_eq!(FloatDuration::milliseconds(250.0).decompose(),
+                   DecomposedTime::from_components(0, 0, 0, 0, 0.25));
+        assert_eq!(FloatDuration::seconds(-90.0).decompose(),
+                   DecomposedTime {
+                       sign: -1,
+                       ..DecomposedTime::from_components(0, 0, 1, 30, 0.0)
+                   });
+        assert_eq!((FloatDuration::days(3.0) + FloatDuration::hours(4.0) +
+                    FloatDuration::minutes(5.0) + FloatDuration::seconds(6.5))
+                       .decompose(),
+                   DecomposedTime::from_components(3, 4, 5, 6, 0.5));
+        assert_eq!(FloatDuration::days(-400.0).decompose(),
+                   DecomposedTime {
+                       sign: -1,
+                       ..DecomposedTime::from_components(400, 0, 0, 0, 0.0)
+                   });
+
+        let almost = FloatDuration::seconds(59.999999999).decompose();
+        assert_eq!((almost.minutes, almost.seconds), (0, 59));
+        assert!(almost.fractional_seconds < 1.0);
+        assert!(almost.fractional_seconds > 0.999);
+    }
+
+    #[test]
+    fn test_decompose_long() {
+        let duration = FloatDuration::years(3.0) + FloatDuration::weeks(5.0) +
+                       FloatDuration::days(6.0) + FloatDuration::hours(7.0) +
+                       FloatDuration::minutes(8.0) + FloatDuration::seconds(9.5);
+        assert_eq!(duration.decompose_long(),
+                   DecomposedTimeLong {
+                       years: 3,
+                       weeks: 5,
+                       days: 6,
+                       hours: 7,
+                       minutes: 8,
+                       seconds: 9,
+                       fractional_seconds: 0.5,
+                       sign: 1,
+                   });
+        assert_eq!(duration.decompose_long().to_float_duration(), duration);
+        assert_eq!((-duration).decompose_long().to_float_duration(), -duration);
+        assert_eq!((-duration).decompose_long().sign, -1);
+
+        // A 365-day year is 52 weeks and a day, so the last day of a year has 52 weeks.
+        let last_day = FloatDuration::days(364.0).decompose_long();
+        assert_eq!((last_day.years, last_day.weeks, last_day.days), (0, 52, 0));
+        let one_year = FloatDuration::days(365.0).decompose_long();
+        assert_eq!((one_year.years, one_year.weeks, one_year.days), (1, 0, 0));
+
+        let zero = FloatDuration::zero().decompose_long();
+        assert_eq!(FloatDuration::from(zero), FloatDuration::zero());
+        assert_eq!((zero.years, zero.weeks, zero.days, zero.hours), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let durations = [FloatDuration::seconds(90.0),
+                         FloatDuration::hours(-26.5),
+                         FloatDuration::days(12.0) + FloatDuration::seconds(0.75),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            assert_eq!(duration.decompose().to_float_duration(), duration);
+        }
+    }
+
+    #[test]
+    fn test_subsecond_components() {
+        let time = DecomposedTime::from_components(0, 0, 0, 5, 0.123456789);
+        assert_eq!(time.milliseconds(), 123);
+        assert_eq!(time.microseconds(), 123_456);
+        assert_eq!(time.nanoseconds(), 123_456_789);
+
+        let time = DecomposedTime::from_components(0, 0, 0, 0, 0.1);
+        assert_eq!((time.milliseconds(), time.microseconds(), time.nanoseconds()),
+                   (100, 100_000, 100_000_000));
+
+        assert_eq!(DecomposedTime::zero().nanoseconds(), 0);
+        let almost = DecomposedTime::from_components(0, 0, 0, 0, 0.9999999999);
+        assert_eq!(almost.nanoseconds(), 999_999_999);
+        assert_eq!(almost.milliseconds(), 999);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(DecomposedTime::from_components(0, 0, 90, 0, 0.0).normalize(),
+                   DecomposedTime::from_components(0, 1, 30, 0, 0.0));
+        assert_eq!(DecomposedTime::from_components(0, 0, 0, 3600, 0.0).normalize(),
+                   DecomposedTime::from_components(0, 1, 0, 0, 0.0));
+        assert_eq!(DecomposedTime::from_components(0, 23, 59, 59, 2.5).normalize(),
+                   DecomposedTime::from_components(1, 0, 0, 1, 0.5));
+        assert_eq!(DecomposedTime::from_components(2, 49, 0, 0, 0.0).normalize(),
+                   DecomposedTime::from_components(4, 1, 0, 0, 0.0));
+
+        let negative = DecomposedTime {
+            sign: -1,
+            ..DecomposedTime::from_components(0, 0, 0, 90, 0.0)
+        };
+        assert_eq!(negative.normalize(),
+                   DecomposedTime {
+                       sign: -1,
+                       ..DecomposedTime::from_components(0, 0, 1, 30, 0.0)
+                   });
+
+        let times = [DecomposedTime::from_components(0, 0, 90, 0, 0.0),
+                     DecomposedTime::from_components(3, 100, 200, 300, 4.25),
+                     DecomposedTime::zero()];
+        for &time in times.iter() {
+            let normalized = time.normalize();
+            assert_eq!(normalized.normalize(), normalized);
+            assert_eq!(normalized.to_float_duration(), time.to_float_duration());
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = DecomposedTime::from_components(1, 2, 0, 0, 0.0);
+        let b = DecomposedTime::from_components(0, 3, 30, 0, 0.0);
+
+        assert_eq!(a + b, DecomposedTime::from_components(1, 5, 30, 0, 0.0));
+        assert_eq!(a - b, DecomposedTime::from_components(0, 22, 30, 0, 0.0));
+        assert_eq!(b - a,
+                   DecomposedTime {
+                       sign: -1,
+                       ..DecomposedTime::from_components(0, 22, 30, 0, 0.0)
+                   });
+        assert_eq!(a - a, DecomposedTime::zero());
+
+        // Sums that overflow a component carry into the next larger unit.
+        let c = DecomposedTime::from_components(0, 0, 45, 30, 0.5);
+        assert_eq!(c + c, DecomposedTime::from_components(0, 1, 31, 1, 0.0));
+        assert_eq!(format!("{}", a + b), "1d 05:30:00");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", DecomposedTime::zero()), "00:00:00");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 2, 3, 4, 0.0)),
+                   "02:03:04");
+        assert_eq!(format!("{}", DecomposedTime::from_components(1, 2, 3, 4, 0.5)),
+                   "1d 02:03:04.5");
+        assert_eq!(format!("{}",
+                           DecomposedTime {
+                               sign: -1,
+                               ..DecomposedTime::from_components(0, 0, 1, 30, 0.25)
+                           }),
+                   "-00:01:30.25");
+
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 0, 0, 0, 1.5)),
+                   "00:00:01.5");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 1, 2, 10, 3.25)),
+                   "01:02:13.25");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 0, 0, 4, 2.0)),
+                   "00:00:06");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 0, 0, 59, 1.5)),
+                   "00:01:00.5");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 23, 59, 59, 1.0)),
+                   "1d 00:00:00");
+        assert_eq!(format!("{}", DecomposedTime::from_components(0, 0, 90, 0, 0.0)),
+                   "01:30:00");
+    }
+
+    #[test]
+    fn test_from_components_milli() {
+        let time = DecomposedTime::from_components_milli(0, 1, 2, 3, 500);
+        assert_eq!(time, DecomposedTime::from_components(0, 1, 2, 3, 0.5));
+        assert_eq!(time.milliseconds(), 500);
+        assert_eq!(format!("{}", time), "01:02:03.5");
+        assert_eq!(format!("{}", DecomposedTime::from_components_milli(2, 0, 0, 0, 0)),
+                   "2d 00:00:00");
+        assert_eq!(DecomposedTime::from_components_milli(0, 0, 0, 1, 999).milliseconds(), 999);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_from_components_milli_panic() {
+        DecomposedTime::from_components_milli(0, 0, 0, 0, 1000);
+    }
+
+    #[test]
+    fn test_display_negative() {
+        // The sign applies to the whole duration, so it precedes the days.
+        let one_day_two_hours = FloatDuration::days(1.0) + FloatDuration::hours(2.0);
+        assert_eq!(format!("{}", (-one_day_two_hours).decompose()), "-1d 02:00:00");
+        assert_eq!(format!("{}", FloatDuration::hours(-2.0).decompose()), "-02:00:00");
+        assert_eq!(format!("{}", FloatDuration::days(-12.5).decompose()), "-12d 12:00:00");
+        assert_eq!(format!("{}", FloatDuration::seconds(-0.5).decompose()), "-00:00:00.5");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("00:00:00".parse::<DecomposedTime>().unwrap(), DecomposedTime::zero());
+        assert_eq!("02:03:04".parse::<DecomposedTime>().unwrap(),
+                   DecomposedTime::from_components(0, 2, 3, 4, 0.0));
+        assert_eq!("1d 02:03:04.5".parse::<DecomposedTime>().unwrap(),
+                   DecomposedTime::from_components(1, 2, 3, 4, 0.5));
+        assert_eq!("-1d 00:00:01".parse::<DecomposedTime>().unwrap(),
+                   DecomposedTime {
+                       sign: -1,
+                       ..DecomposedTime::from_components(1, 0, 0, 1, 0.0)
+                   });
+        assert_eq!("00:90:00".parse::<DecomposedTime>().unwrap(),
+                   DecomposedTime::from_components(0, 0, 90, 0, 0.0));
+
+        let invalid = ["",
+                       "02:03",
+                       "02:03:04:05",
+                       "2h 03:04:05",
+                       "1d02:03:04",
+                       "-+01:02:03",
+                       "01:02:03.",
+                       "01:02:03.-5",
+                       "01:02:03.5e3",
+                       "aa:bb:cc",
+                       "01: 02:03"];
+        for s in invalid.iter() {
+            assert!(s.parse::<DecomposedTime>().is_err(), "{:?} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        let times = [DecomposedTime::zero(),
+                     DecomposedTime::from_components(0, 2, 3, 4, 0.0),
+                     DecomposedTime::from_components(1, 2, 3, 4, 0.5),
+                     DecomposedTime::from_components(400, 23, 59, 59, 0.125),
+                     FloatDuration::seconds(-90.25).decompose(),
+                     (-FloatDuration::days(3.0) - FloatDuration::hours(5.0)).decompose()];
+        for &time in times.iter() {
+            assert_eq!(format!("{}", time).parse::<DecomposedTime>().unwrap(), time);
+        }
+    }
+}
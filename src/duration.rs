@@ -1,10 +1,19 @@
 //! Floating-point duration type `FloatDuration` and helpers.
+// `Duration` lives in `core`; `Instant` and `SystemTime` need `std`.
+#[cfg(feature = "std")]
 use std::time;
-use std::fmt;
-use std::ops;
-use std::f64;
-use std::u64;
-use std::iter::Sum;
+#[cfg(not(feature = "std"))]
+use core::time;
+use core::cmp;
+use core::fmt;
+use core::ops;
+use core::f64;
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::str::FromStr;
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::thread;
 
 #[cfg(feature = "chrono")]
 use chrono;
@@ -17,6 +26,9 @@ use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use serde::de::{self, Visitor};
 
 use super::error;
+#[cfg(feature = "std")]
+use super::decomposed::{DecomposedTime, DecomposedTimeLong};
+use super::format::TimeUnit;
 
 /// Number of nanoseconds in a second.
 pub const NANOS_PER_SEC: f64 = 1.0e9;
@@ -30,8 +42,14 @@ pub const SECS_PER_MINUTE: f64 = 60.0;
 pub const SECS_PER_HOUR: f64 = SECS_PER_MINUTE * 60.0;
 /// Number of seconds in a day.
 pub const SECS_PER_DAY: f64 = SECS_PER_HOUR * 24.0;
+/// Number of seconds in a week.
+pub const SECS_PER_WEEK: f64 = SECS_PER_DAY * 7.0;
 /// Number of seconds in a year.
 pub const SECS_PER_YEAR: f64 = SECS_PER_DAY * 365.0;
+/// Number of seconds in a decade.
+pub const SECS_PER_DECADE: f64 = SECS_PER_YEAR * 10.0;
+/// Number of seconds in a century.
+pub const SECS_PER_CENTURY: f64 = SECS_PER_YEAR * 100.0;
 
 /// A fallible conversion from one duration representation to another.
 ///
@@ -72,6 +90,7 @@ impl<T, U> IntoDuration<U> for T
 ///
 /// #Examples
 /// ```rust
+/// # #[cfg(feature = "std")] {
 /// # fn perform_expensive_task() {}
 /// // Don't forget to use TimePoint.
 /// use float_duration::{TimePoint, FloatDuration};
@@ -80,10 +99,12 @@ impl<T, U> IntoDuration<U> for T
 /// let start = Instant::now();
 /// perform_expensive_task();
 ///
-/// // Computing a duration using `Instant` cannot fail, so it is safe to unwrap.
+/// // `Instant` is monotonic, so `now` is never earlier than `start` and it is safe
+/// // to unwrap.
 /// let elapsed = Instant::now().float_duration_since(start).unwrap();
 ///
 /// println!("Took {}.", elapsed);
+/// # }
 /// ```
 pub trait TimePoint<Rhs = Self> {
     /// The type returned if there is an error computing the duration.
@@ -92,6 +113,23 @@ pub trait TimePoint<Rhs = Self> {
     fn float_duration_since(self, rhs: Rhs) -> Result<FloatDuration, Self::Error>;
 }
 
+/// Clock readings that can report the time elapsed since they were taken.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, TimePointExt};
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// assert!(start.elapsed_float() >= FloatDuration::zero());
+/// ```
+#[cfg(feature = "std")]
+pub trait TimePointExt {
+    /// The type returned by `elapsed_float`.
+    type Output;
+    /// The amount of time between this time point and now.
+    fn elapsed_float(&self) -> Self::Output;
+}
+
 /// A time duration stored as a floating point quantity.
 ///
 /// Unlike `std::time::Duration` or `chrono::Duration`, `FloatDuration`
@@ -101,6 +139,15 @@ pub trait TimePoint<Rhs = Self> {
 ///
 /// Internally, a `FloatDuration` stores a single `f64` number of floating-point seconds,
 /// thus it is only as precise as the `f64` type.
+///
+/// The unit constructors are `const fn`, so durations may be used in constants:
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// const TIMEOUT: FloatDuration = FloatDuration::seconds(30.0);
+/// assert_eq!(TIMEOUT.as_minutes(), 0.5);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct FloatDuration {
     secs: f64,
@@ -108,50 +155,192 @@ pub struct FloatDuration {
 
 
 impl FloatDuration {
+    /// Create a new `FloatDuration` representing a number of centuries.
+    ///
+    /// A century is exactly 100 years of 365 days, with no consideration of
+    /// leap years.
+    #[inline]
+    pub const fn centuries(centuries: f64) -> FloatDuration {
+        FloatDuration { secs: centuries * SECS_PER_CENTURY }
+    }
+    /// Create a new `FloatDuration` representing a number of decades.
+    ///
+    /// A decade is exactly 10 years of 365 days, with no consideration of
+    /// leap years.
+    #[inline]
+    pub const fn decades(decades: f64) -> FloatDuration {
+        FloatDuration { secs: decades * SECS_PER_DECADE }
+    }
     /// Create a new `FloatDuration` representing a number of years.
     ///
     /// `float_duration` considers one year to be exactly 365 days, with
     /// no consideration of leap years.
     #[inline]
-    pub fn years(years: f64) -> FloatDuration {
+    pub const fn years(years: f64) -> FloatDuration {
         FloatDuration { secs: years * SECS_PER_YEAR }
     }
+    /// Create a new `FloatDuration` representing a number of weeks.
+    #[inline]
+    pub const fn weeks(weeks: f64) -> FloatDuration {
+        FloatDuration { secs: weeks * SECS_PER_WEEK }
+    }
     /// Create a new `FloatDuration` representing a number of days.
     #[inline]
-    pub fn days(days: f64) -> FloatDuration {
+    pub const fn days(days: f64) -> FloatDuration {
         FloatDuration { secs: days * SECS_PER_DAY }
     }
     /// Create a new `FloatDuration` representing a number of hours.
     #[inline]
-    pub fn hours(hours: f64) -> FloatDuration {
+    pub const fn hours(hours: f64) -> FloatDuration {
         FloatDuration { secs: hours * SECS_PER_HOUR }
     }
     /// Create a new `FloatDuration` representing a number of minutes.
     #[inline]
-    pub fn minutes(mins: f64) -> FloatDuration {
+    pub const fn minutes(mins: f64) -> FloatDuration {
         FloatDuration { secs: mins * SECS_PER_MINUTE }
     }
     /// Create a new `FloatDuration` representing a number of seconds.
     #[inline]
-    pub fn seconds(secs: f64) -> FloatDuration {
-        FloatDuration { secs: secs }
+    pub const fn seconds(secs: f64) -> FloatDuration {
+        FloatDuration { secs }
     }
     /// Create a new `FloatDuration` representing a number of milliseconds.
     #[inline]
-    pub fn milliseconds(millis: f64) -> FloatDuration {
+    pub const fn milliseconds(millis: f64) -> FloatDuration {
         FloatDuration { secs: millis / MILLIS_PER_SEC }
     }
     /// Create a new `FloatDuration` representing a number of microseconds.
     #[inline]
-    pub fn microseconds(micros: f64) -> FloatDuration {
+    pub const fn microseconds(micros: f64) -> FloatDuration {
         FloatDuration { secs: micros / MICROS_PER_SEC }
     }
     /// Create a new `FloatDuration` representing a number of nanoseconds.
     #[inline]
-    pub fn nanoseconds(nanos: f64) -> FloatDuration {
+    pub const fn nanoseconds(nanos: f64) -> FloatDuration {
         FloatDuration { secs: nanos / NANOS_PER_SEC }
     }
+    /// Create a new `FloatDuration` representing a number of centuries, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// Returns an `OutOfRangeError` if the number of seconds overflows to infinity,
+    /// or if `centuries` is itself infinite or NaN.
+    #[inline]
+    pub fn try_centuries(centuries: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::centuries(centuries).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of decades, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_decades(decades: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::decades(decades).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of years, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_years(years: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::years(years).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of weeks, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_weeks(weeks: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::weeks(weeks).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of days, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_days(days: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::days(days).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of hours, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_hours(hours: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::hours(hours).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of minutes, checking
+    /// that the result is finite.
+    ///
+    /// # Errors
+    /// See `try_centuries`.
+    #[inline]
+    pub fn try_minutes(mins: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::minutes(mins).finite_or_err()
+    }
+    /// Create a new `FloatDuration` representing a number of seconds, checking
+    /// that it is finite.
+    ///
+    /// This is the checked counterpart of `From<f64>`, which accepts any value.
+    ///
+    /// # Errors
+    /// Returns an `OutOfRangeError` if `secs` is infinite or NaN.
+    #[inline]
+    pub fn try_seconds(secs: f64) -> Result<FloatDuration, error::OutOfRangeError> {
+        FloatDuration::seconds(secs).finite_or_err()
+    }
+    #[inline]
+    fn finite_or_err(self) -> Result<FloatDuration, error::OutOfRangeError> {
+        if self.is_finite() {
+            Ok(self)
+        } else {
+            Err(error::OutOfRangeError::new())
+        }
+    }
+    /// Create a new `FloatDuration` from a number of hours, minutes and seconds.
+    ///
+    /// The components are not validated against clock limits, so `from_hms(0, 90, 0.0)`
+    /// is simply ninety minutes.
+    #[inline]
+    pub const fn from_hms(hours: u32, minutes: u32, seconds: f64) -> FloatDuration {
+        FloatDuration {
+            secs: hours as f64 * SECS_PER_HOUR + minutes as f64 * SECS_PER_MINUTE + seconds,
+        }
+    }
+    /// Create a new `FloatDuration` from a number of hours, minutes, seconds and
+    /// milliseconds.
+    ///
+    /// As with `from_hms`, the components are not validated against clock limits.
+    #[inline]
+    pub const fn from_hms_milli(hours: u32,
+                                minutes: u32,
+                                seconds: u32,
+                                millis: u32)
+                                -> FloatDuration {
+        FloatDuration::from_hms(hours, minutes, seconds as f64 + millis as f64 / MILLIS_PER_SEC)
+    }
 
+    /// Return the total number of fractional centuries represented by the `FloatDuration`.
+    ///
+    /// A century is exactly 100 years of 365 days, with no consideration of
+    /// leap years.
+    #[inline]
+    pub fn as_centuries(&self) -> f64 {
+        self.secs / SECS_PER_CENTURY
+    }
+    /// Return the total number of fractional decades represented by the `FloatDuration`.
+    ///
+    /// A decade is exactly 10 years of 365 days, with no consideration of
+    /// leap years.
+    #[inline]
+    pub fn as_decades(&self) -> f64 {
+        self.secs / SECS_PER_DECADE
+    }
     /// Return the total number of fractional years represented by the `FloatDuration`.
     ///
     /// `float_duration` considers one year to be exactly 365 days, with
@@ -160,6 +349,11 @@ impl FloatDuration {
     pub fn as_years(&self) -> f64 {
         self.secs / SECS_PER_YEAR
     }
+    /// Return the total number of fractional weeks represented by the `FloatDuration`.
+    #[inline]
+    pub fn as_weeks(&self) -> f64 {
+        self.secs / SECS_PER_WEEK
+    }
     /// Return the total number of fractional days represented by the `FloatDuration`.
     #[inline]
     pub fn as_days(&self) -> f64 {
@@ -195,15 +389,190 @@ impl FloatDuration {
     pub fn as_nanoseconds(&self) -> f64 {
         self.secs * NANOS_PER_SEC
     }
+    /// Return the length of this duration in every unit at once.
+    ///
+    /// Each field of the result is equal to the corresponding `as_*` accessor.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let units = FloatDuration::minutes(90.0).as_all_units();
+    /// assert_eq!(units.hours, 1.5);
+    /// assert_eq!(units.seconds, 5400.0);
+    /// ```
+    pub fn as_all_units(&self) -> AllUnits {
+        AllUnits {
+            years: self.as_years(),
+            days: self.as_days(),
+            hours: self.as_hours(),
+            minutes: self.as_minutes(),
+            seconds: self.as_seconds(),
+            millis: self.as_milliseconds(),
+            micros: self.as_microseconds(),
+            nanos: self.as_nanoseconds(),
+        }
+    }
+    /// Split this duration into whole seconds and a fractional second.
+    ///
+    /// Both parts have the same sign as the duration, so `-1.25` seconds splits into
+    /// `(-1, -0.25)`, and the fractional part lies in `(-1, 1)`. Durations beyond the
+    /// range of `i64` saturate the whole seconds at `i64::MIN` or `i64::MAX`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn split_seconds(&self) -> (i64, f64) {
+        (self.secs.trunc() as i64, self.secs.fract())
+    }
+    /// Split this duration into whole seconds and a whole number of nanoseconds.
+    ///
+    /// As with `split_seconds`, both parts carry the sign of the duration, so
+    /// `-1.25` seconds becomes `(-1, -250_000_000)` and the nanoseconds lie in
+    /// `(-1_000_000_000, 1_000_000_000)`. The nanoseconds are rounded to the nearest
+    /// integer, carrying into the seconds if necessary.
+    #[cfg(feature = "std")]
+    pub fn as_secs_nanos(&self) -> (i64, i32) {
+        let (secs, fraction) = self.split_seconds();
+        let nanos = (fraction * NANOS_PER_SEC).round() as i32;
+        if nanos.abs() == NANOS_PER_SEC as i32 {
+            (secs.saturating_add(nanos.signum() as i64), 0)
+        } else {
+            (secs, nanos)
+        }
+    }
+    /// Create a new `FloatDuration` from whole seconds and nanoseconds.
+    ///
+    /// The two parts are simply added, so the inverse of `as_secs_nanos` is obtained
+    /// by giving both the same sign.
+    #[inline]
+    pub const fn from_secs_nanos(secs: i64, nanos: i32) -> FloatDuration {
+        FloatDuration { secs: secs as f64 + nanos as f64 / NANOS_PER_SEC }
+    }
+    /// Split this duration into the fields of a POSIX `timespec`.
+    ///
+    /// Following the POSIX normalization, the nanoseconds lie in
+    /// `[0, 1_000_000_000)` and the seconds carry the sign, so `-1.25` seconds
+    /// becomes `(-2, 750_000_000)`. The nanoseconds are rounded to the nearest
+    /// integer. Durations beyond the range of `i64` saturate the seconds.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::seconds(-1.25).to_timespec(), (-2, 750_000_000));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_timespec(&self) -> (i64, i64) {
+        let secs = self.secs.floor();
+        let nanos = ((self.secs - secs) * NANOS_PER_SEC).round() as i64;
+        if nanos >= NANOS_PER_SEC as i64 {
+            ((secs as i64).saturating_add(1), 0)
+        } else {
+            (secs as i64, nanos)
+        }
+    }
+    /// Create a new `FloatDuration` from the fields of a POSIX `timespec`.
+    ///
+    /// The fields are simply added, so `nsec` need not be normalized.
+    #[inline]
+    pub const fn from_timespec(sec: i64, nsec: i64) -> FloatDuration {
+        FloatDuration { secs: sec as f64 + nsec as f64 / NANOS_PER_SEC }
+    }
+    /// Return the total number of nanoseconds represented by the `FloatDuration`,
+    /// rounded to the nearest integer.
+    ///
+    /// Returns `None` if the duration is infinite or NaN, or if the number of
+    /// nanoseconds does not fit in an `i128`.
+    #[cfg(feature = "std")]
+    pub fn as_nanoseconds_i128(&self) -> Option<i128> {
+        let nanos = (self.secs * NANOS_PER_SEC).round();
+        // `i128::MAX as f64` rounds up to exactly 2^127, which is itself out of range.
+        if nanos.is_finite() && nanos.abs() < i128::MAX as f64 {
+            Some(nanos as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `FloatDuration` spanning `frames` frames at `fps` frames per second.
+    ///
+    /// As with division, an `fps` of zero yields an infinite duration.
+    #[inline]
+    pub fn from_frames(frames: f64, fps: f64) -> FloatDuration {
+        FloatDuration::seconds(frames / fps)
+    }
+    /// Return the fractional number of frames at `fps` frames per second that fit in
+    /// this duration.
+    #[inline]
+    pub fn as_frames(&self, fps: f64) -> f64 {
+        self.secs * fps
+    }
+    /// Round this duration to the nearest whole number of frames at `fps` frames
+    /// per second.
+    ///
+    /// An `fps` of zero has no frame boundaries, and yields NaN.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let snapped = FloatDuration::seconds(1.017).quantize_to_fps(30.0);
+    /// assert_eq!(snapped, FloatDuration::from_frames(31.0, 30.0));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn quantize_to_fps(&self, fps: f64) -> FloatDuration {
+        FloatDuration::from_frames(self.as_frames(fps).round(), fps)
+    }
+    /// Create a new `FloatDuration` spanning `beats` beats at a tempo of `bpm` beats
+    /// per minute.
+    ///
+    /// One beat at 120 BPM lasts half a second.
+    #[inline]
+    pub fn from_beats(beats: f64, bpm: f64) -> FloatDuration {
+        FloatDuration::minutes(beats / bpm)
+    }
+    /// Return the fractional number of beats at a tempo of `bpm` beats per minute
+    /// that fit in this duration.
+    #[inline]
+    pub fn as_beats(&self, bpm: f64) -> f64 {
+        self.as_minutes() * bpm
+    }
+    /// Return the rate, in units per second, at which `quantity` units are
+    /// processed over this duration.
+    ///
+    /// As with division, the rate over a zero duration is infinite (or NaN if
+    /// `quantity` is also zero).
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let bytes_per_sec = FloatDuration::seconds(4.0).rate(1024.0);
+    /// assert_eq!(bytes_per_sec, 256.0);
+    /// ```
+    #[inline]
+    pub fn rate(&self, quantity: f64) -> f64 {
+        quantity / self.secs
+    }
+    /// Create a new `FloatDuration` spanning the time needed to process `quantity`
+    /// units at `rate` units per second.
+    ///
+    /// This is the inverse of `rate`. A `rate` of zero yields an infinite duration.
+    #[inline]
+    pub fn time_for(quantity: f64, rate: f64) -> FloatDuration {
+        FloatDuration::seconds(quantity / rate)
+    }
 
     /// Compute the absolute value of this duration.
     #[inline]
     pub fn abs(self) -> FloatDuration {
         FloatDuration { secs: self.secs.abs() }
     }
+    /// Return a new `FloatDurationBuilder` for composing a duration from integer
+    /// parts.
+    #[inline]
+    pub fn builder() -> FloatDurationBuilder {
+        FloatDurationBuilder::new()
+    }
     /// Return a new `FloatDuration` that represents zero elapsed time.
     #[inline]
-    pub fn zero() -> FloatDuration {
+    pub const fn zero() -> FloatDuration {
         FloatDuration { secs: 0.0 }
     }
     /// Returns true is this duration represents zero elapsed time (equals `FloatDuration::zero()`).
@@ -221,16 +590,42 @@ impl FloatDuration {
     pub fn is_negative(&self) -> bool {
         self.secs.is_sign_negative()
     }
+    /// Returns true if the FloatDuration is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.secs.is_finite()
+    }
+    /// Returns true if the FloatDuration is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        self.secs.is_infinite()
+    }
+    /// Returns true if the FloatDuration is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.secs.is_nan()
+    }
 
     /// Return a number that represents the sign of `self`.
     ///
     /// - 1.0 if the value is positive, `+0.0` or `INFINITY`
     /// - -1.0 if the value is negative, `-0.0` or `NEG_INFINITY`
     /// - `NAN` if the value is `NAN`
+    ///
+    /// This follows `f64::signum` and never returns `0.0`; use `is_zero` to
+    /// distinguish a zero duration.
     #[inline]
     pub fn signum(&self) -> f64 {
         self.secs.signum()
     }
+    /// Return a duration with the magnitude of `self` and the sign of `sign_source`.
+    ///
+    /// This follows `f64::copysign`, so the sign of a zero `sign_source` is
+    /// respected: `a.copysign(-FloatDuration::zero())` is `-|a|`.
+    #[inline]
+    pub fn copysign(self, sign_source: FloatDuration) -> FloatDuration {
+        FloatDuration { secs: self.secs.copysign(sign_source.secs) }
+    }
 
     /// Return the maximum of two durations.
     #[inline]
@@ -242,152 +637,704 @@ impl FloatDuration {
     pub fn min(self, other: FloatDuration) -> FloatDuration {
         FloatDuration { secs: self.secs.min(other.secs) }
     }
-
-    /// Return a new `FloatDuration` with the minimum possible value.
+    /// Return the magnitude of the difference between two durations.
+    ///
+    /// This is equivalent to `(self - other).abs()`, and is symmetric in its
+    /// arguments.
     #[inline]
-    pub fn min_value() -> FloatDuration {
-        FloatDuration { secs: f64::MIN }
+    pub fn abs_diff(self, other: FloatDuration) -> FloatDuration {
+        (self - other).abs()
     }
-    /// Return a new `FloatDuration` with the maximum possible value.
+    /// Return true if this duration differs from `other` by no more than `tolerance`.
+    ///
+    /// This is available whether or not the `approx` feature is enabled.
     #[inline]
-    pub fn max_value() -> FloatDuration {
-        FloatDuration { secs: f64::MAX }
+    pub fn approx_eq(&self, other: &FloatDuration, tolerance: FloatDuration) -> bool {
+        self.abs_diff(*other) <= tolerance
     }
-
-    /// Create a `std::time::Duration` object from a `FloatDuration`.
+    /// Return true if this duration differs from `other` by no more than `epsilon`.
     ///
-    /// # Errors
-    /// `std::time::Duration` does not support negative values or seconds
-    /// greater than `std::u64::MAX`. This function will return a
-    /// `DurationError::StdOutOfRange` if the `FloatDuration` value is outside
-    /// of either of those bounds.
-    pub fn to_std(&self) -> Result<time::Duration, error::OutOfRangeError> {
-        if self.secs.is_sign_negative() {
-            Err(error::OutOfRangeError::new())
-        } else {
-            let seconds = self.secs.trunc();
-            let nanos = self.secs.fract() * NANOS_PER_SEC;
-
-            if seconds > u64::MAX as f64 {
-                Err(error::OutOfRangeError::new())
-            } else {
-                Ok(time::Duration::new(seconds as u64, nanos as u32))
-            }
-        }
+    /// Together with `lt_within` and `gt_within`, this partitions comparisons
+    /// into three cases: for any durations other than NaN, exactly one of the
+    /// three returns true.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let epsilon = FloatDuration::nanoseconds(1.0);
+    /// let sum = FloatDuration::seconds(0.1) + FloatDuration::seconds(0.2);
+    /// assert!(sum.eq_within(FloatDuration::seconds(0.3), epsilon));
+    /// assert!(!sum.gt_within(FloatDuration::seconds(0.3), epsilon));
+    /// ```
+    #[inline]
+    pub fn eq_within(&self, other: FloatDuration, epsilon: FloatDuration) -> bool {
+        self.approx_eq(&other, epsilon)
     }
-
-    /// Create a `FloatDuration` object from a `std::time::Duration`.
+    /// Return true if this duration is less than `other` by more than `epsilon`.
+    #[inline]
+    pub fn lt_within(&self, other: FloatDuration, epsilon: FloatDuration) -> bool {
+        other - *self > epsilon
+    }
+    /// Return true if this duration is greater than `other` by more than `epsilon`.
+    #[inline]
+    pub fn gt_within(&self, other: FloatDuration, epsilon: FloatDuration) -> bool {
+        *self - other > epsilon
+    }
+    /// Return the ratio of this duration to `whole`.
     ///
-    /// Equivalent to using `FloatDuration::from(duration)`
+    /// This is equivalent to `*self / whole`. If `whole` is zero, the result is
+    /// infinite, or NaN if this duration is also zero.
     #[inline]
-    pub fn from_std(duration: time::Duration) -> FloatDuration {
-        FloatDuration::seconds((duration.as_secs() as f64) +
-                               (duration.subsec_nanos() as f64) / NANOS_PER_SEC)
+    pub fn ratio_to(&self, whole: FloatDuration) -> f64 {
+        self.secs / whole.secs
     }
-}
-
-#[cfg(feature = "chrono")]
-impl FloatDuration {
-    /// Create a `chrono::Duration` object from a `FloatDuration`.
+    /// Return this duration as a percentage of `whole`.
     ///
-    /// # Errors
-    /// Presently, the conversion to `chrono::Duration` first goes through
-    /// `std::time::Duration` and return an error if `to_std` returns an error.
-    pub fn to_chrono(&self) -> Result<chrono::Duration, error::OutOfRangeError> {
-        let is_negative = self.is_negative();
-        let std_duration = self.abs().to_std()?;
-        let chrono_duration = chrono::Duration::from_std(std_duration)?;
-        if is_negative {
-            Ok(-chrono_duration)
-        } else {
-            Ok(chrono_duration)
-        }
+    /// This is `100.0 * self.ratio_to(whole)`, with the same behavior when `whole`
+    /// is zero.
+    #[inline]
+    pub fn percent_of(&self, whole: FloatDuration) -> f64 {
+        100.0 * self.ratio_to(whole)
     }
-
-    /// Create a `FloatDuration` object from a `chrono::Duration`.
+    /// Linearly interpolate between this duration and `other`.
     ///
-    /// `chrono::Duration` does not provide a way to access sub-millisecond
-    /// precision if the duration is too large to be entirely represented as a single
-    /// value. Thus, if the absolute value of the total number of nanoseconds is
-    /// greater than `i64::MAX`, only millisecond precision will be captured.
+    /// A `t` of `0.0` returns `self` and a `t` of `1.0` returns `other`. Values of
+    /// `t` outside `[0, 1]` are accepted and extrapolate beyond the endpoints.
+    #[inline]
+    pub fn lerp(self, other: FloatDuration, t: f64) -> FloatDuration {
+        FloatDuration { secs: (1.0 - t) * self.secs + t * other.secs }
+    }
+    /// Compute `self * factor + addend` with a single rounding error.
     ///
-    /// Equivalent to using `FloatDuration::from(duration)`
+    /// This uses `f64::mul_add`, which is more accurate than the separate
+    /// multiplication and addition, and may be faster on platforms with a fused
+    /// multiply-add instruction.
+    #[cfg(feature = "std")]
     #[inline]
-    pub fn from_chrono(duration: chrono::Duration) -> FloatDuration {
-        if let Some(nanos) = duration.num_nanoseconds() {
-            FloatDuration::nanoseconds(nanos as f64)
-        } else {
-            FloatDuration::milliseconds(duration.num_milliseconds() as f64)
-        }
+    pub fn mul_add(self, factor: f64, addend: FloatDuration) -> FloatDuration {
+        FloatDuration { secs: self.secs.mul_add(factor, addend.secs) }
+    }
+    /// Restrict this duration to the interval `[min, max]`.
+    ///
+    /// # Panics
+    /// Like `f64::clamp`, this function panics if `min > max` or if either bound
+    /// is NaN.
+    #[inline]
+    pub fn clamp(self, min: FloatDuration, max: FloatDuration) -> FloatDuration {
+        FloatDuration { secs: self.secs.clamp(min.secs, max.secs) }
     }
-}
-
-#[cfg(feature = "serde")]
-struct FloatDurationVisitor;
-
-// We want to serialize a `FloatDuration` as a single f64 instead of a struct.
-#[cfg(feature = "serde")]
-impl<'de> Visitor<'de> for FloatDurationVisitor {
-    type Value = FloatDuration;
 
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a floating-point value")
+    /// Round this duration to the nearest multiple of `unit`.
+    ///
+    /// Ties are rounded away from zero, as with `f64::round`. If `unit` is zero,
+    /// `self` is returned unchanged. The sign of `unit` is ignored.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::seconds(95.0).round_to(FloatDuration::minutes(1.0)),
+    ///            FloatDuration::minutes(2.0));
+    /// ```
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn round_to(&self, unit: FloatDuration) -> FloatDuration {
+        self.snap_to(unit, f64::round)
     }
-    fn visit_f32<E>(self, value: f32) -> Result<FloatDuration, E>
-        where E: de::Error
-    {
-        Ok(FloatDuration::seconds(value as f64))
+    /// Round this duration down to the nearest multiple of `unit`.
+    ///
+    /// Negative durations are rounded toward negative infinity, as with `f64::floor`.
+    /// If `unit` is zero, `self` is returned unchanged. The sign of `unit` is ignored.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn floor_to(&self, unit: FloatDuration) -> FloatDuration {
+        self.snap_to(unit, f64::floor)
     }
-    fn visit_f64<E>(self, value: f64) -> Result<FloatDuration, E>
-        where E: de::Error
-    {
-        Ok(FloatDuration::seconds(value))
+    /// Round this duration up to the nearest multiple of `unit`.
+    ///
+    /// Negative durations are rounded toward positive infinity, as with `f64::ceil`.
+    /// If `unit` is zero, `self` is returned unchanged. The sign of `unit` is ignored.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn ceil_to(&self, unit: FloatDuration) -> FloatDuration {
+        self.snap_to(unit, f64::ceil)
     }
-}
-
-#[cfg(feature = "serde")]
-impl Serialize for FloatDuration {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where S: Serializer
+    #[inline]
+    #[cfg(feature = "std")]
+    fn snap_to<F>(&self, unit: FloatDuration, rounding: F) -> FloatDuration
+        where F: Fn(f64) -> f64
     {
-        serializer.serialize_f64(self.secs)
+        let unit = unit.secs.abs();
+        if unit == 0.0 {
+            *self
+        } else {
+            FloatDuration { secs: rounding(self.secs / unit) * unit }
+        }
     }
-}
-
-#[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for FloatDuration {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where D: Deserializer<'de>
-    {
-        deserializer.deserialize_f64(FloatDurationVisitor)
+    /// Wrap this duration into the interval `[0, period)`.
+    ///
+    /// Unlike the `%` operator, which keeps the sign of `self`, negative durations
+    /// wrap around from the end of the period, so `-1` hour wrapped to a period of
+    /// 24 hours is 23 hours. This is the Euclidean remainder, and is useful for
+    /// time-of-day calculations. The sign of `period` is ignored, and a zero period
+    /// gives NaN.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::hours(-1.0).wrap_to(FloatDuration::days(1.0)),
+    ///            FloatDuration::hours(23.0));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn wrap_to(&self, period: FloatDuration) -> FloatDuration {
+        let period = period.secs.abs();
+        let wrapped = self.secs.rem_euclid(period);
+        // Rounding can produce exactly `period` for tiny negative values.
+        if wrapped >= period {
+            FloatDuration::zero()
+        } else {
+            FloatDuration { secs: wrapped }
+        }
     }
-}
 
-#[cfg(feature = "chrono")]
-impl<Tz: chrono::TimeZone> TimePoint for chrono::DateTime<Tz> {
-    type Error = ();
+    /// Add two durations, returning `None` if the result is not finite.
     #[inline]
-    fn float_duration_since(self, since: chrono::DateTime<Tz>) -> Result<FloatDuration, ()> {
-        let chrono_duration = self.signed_duration_since(since);
-        Ok(FloatDuration::from_chrono(chrono_duration))
+    pub fn checked_add(self, rhs: FloatDuration) -> Option<FloatDuration> {
+        FloatDuration::checked_result(self.secs + rhs.secs)
     }
-}
-#[cfg(feature = "chrono")]
-impl<Tz: chrono::TimeZone> TimePoint for chrono::Date<Tz> {
-    type Error = ();
+    /// Subtract two durations, returning `None` if the result is not finite.
     #[inline]
-    fn float_duration_since(self, since: chrono::Date<Tz>) -> Result<FloatDuration, ()> {
-        let chrono_duration = self.signed_duration_since(since);
-        Ok(FloatDuration::from_chrono(chrono_duration))
+    pub fn checked_sub(self, rhs: FloatDuration) -> Option<FloatDuration> {
+        FloatDuration::checked_result(self.secs - rhs.secs)
     }
-}
-#[cfg(feature = "chrono")]
-impl TimePoint for chrono::NaiveDate {
-    type Error = ();
+    /// Multiply a duration by a scalar, returning `None` if the result is not finite.
     #[inline]
-    fn float_duration_since(self, since: chrono::NaiveDate) -> Result<FloatDuration, ()> {
-        let chrono_duration = self.signed_duration_since(since);
-        Ok(FloatDuration::from_chrono(chrono_duration))
+    pub fn checked_mul(self, rhs: f64) -> Option<FloatDuration> {
+        FloatDuration::checked_result(self.secs * rhs)
+    }
+    /// Divide a duration by a scalar, returning `None` if the result is not finite.
+    ///
+    /// In particular, this returns `None` when dividing by zero.
+    #[inline]
+    pub fn checked_div(self, rhs: f64) -> Option<FloatDuration> {
+        FloatDuration::checked_result(self.secs / rhs)
+    }
+    #[inline]
+    fn checked_result(secs: f64) -> Option<FloatDuration> {
+        if secs.is_finite() {
+            Some(FloatDuration { secs })
+        } else {
+            None
+        }
+    }
+
+    /// Add two durations, clamping the result to the range
+    /// `[min_value(), max_value()]` instead of overflowing to infinity.
+    ///
+    /// A NaN result is returned unchanged.
+    #[inline]
+    pub fn saturating_add(self, rhs: FloatDuration) -> FloatDuration {
+        FloatDuration::saturated_result(self.secs + rhs.secs)
+    }
+    /// Subtract two durations, clamping the result to the range
+    /// `[min_value(), max_value()]` instead of overflowing to infinity.
+    ///
+    /// A NaN result is returned unchanged.
+    #[inline]
+    pub fn saturating_sub(self, rhs: FloatDuration) -> FloatDuration {
+        FloatDuration::saturated_result(self.secs - rhs.secs)
+    }
+    #[inline]
+    fn saturated_result(secs: f64) -> FloatDuration {
+        if secs == f64::INFINITY {
+            FloatDuration::max_value()
+        } else if secs == f64::NEG_INFINITY {
+            FloatDuration::min_value()
+        } else {
+            FloatDuration { secs }
+        }
+    }
+
+    /// Compare two durations using the IEEE 754 `totalOrder` predicate.
+    ///
+    /// Unlike `partial_cmp`, this always produces an ordering, so it can be used to
+    /// sort durations: `durations.sort_by(FloatDuration::total_cmp)`. Negative zero
+    /// orders before positive zero, and NaN values are placed at the ends: positive
+    /// NaN after all other values and negative NaN before them.
+    #[inline]
+    pub fn total_cmp(&self, other: &FloatDuration) -> cmp::Ordering {
+        self.secs.total_cmp(&other.secs)
+    }
+
+    /// Return a new `FloatDuration` with the minimum possible value.
+    #[inline]
+    pub const fn min_value() -> FloatDuration {
+        FloatDuration { secs: f64::MIN }
+    }
+    /// Return a new `FloatDuration` with the maximum possible value.
+    #[inline]
+    pub const fn max_value() -> FloatDuration {
+        FloatDuration { secs: f64::MAX }
+    }
+
+    /// Break this duration down into days, hours, minutes and seconds.
+    ///
+    /// The components of the returned `DecomposedTime` are computed from the absolute
+    /// value of the duration, with its direction stored in `sign`. The fractional
+    /// seconds are always in the range `[0, 1)`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let time = FloatDuration::seconds(-90.0).decompose();
+    /// assert_eq!((time.minutes, time.seconds, time.sign), (1, 30, -1));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn decompose(&self) -> DecomposedTime {
+        let abs_secs = self.secs.abs();
+        let whole_secs = abs_secs.trunc() as u64;
+        let secs_per_day = SECS_PER_DAY as u64;
+        let secs_per_hour = SECS_PER_HOUR as u64;
+        let secs_per_minute = SECS_PER_MINUTE as u64;
+
+        let time_of_day = whole_secs % secs_per_day;
+        DecomposedTime {
+            days: whole_secs / secs_per_day,
+            hours: (time_of_day / secs_per_hour) as u32,
+            minutes: (time_of_day % secs_per_hour / secs_per_minute) as u32,
+            seconds: (time_of_day % secs_per_minute) as u32,
+            fractional_seconds: abs_secs.fract(),
+            sign: if self.secs < 0.0 { -1 } else { 1 },
+        }
+    }
+    /// Break this duration down into years, weeks, days, hours, minutes and seconds.
+    ///
+    /// This is the same as `decompose`, except that the whole days are further
+    /// carried into 365-day years first, and the remaining days into 7-day weeks.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let time = FloatDuration::days(2.0 * 365.0 + 10.0).decompose_long();
+    /// assert_eq!((time.years, time.weeks, time.days), (2, 1, 3));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn decompose_long(&self) -> DecomposedTimeLong {
+        let time = self.decompose();
+        let days_per_year = (SECS_PER_YEAR / SECS_PER_DAY) as u64;
+        let day_of_year = time.days % days_per_year;
+        DecomposedTimeLong {
+            years: time.days / days_per_year,
+            weeks: (day_of_year / 7) as u32,
+            days: (day_of_year % 7) as u32,
+            hours: time.hours,
+            minutes: time.minutes,
+            seconds: time.seconds,
+            fractional_seconds: time.fractional_seconds,
+            sign: time.sign,
+        }
+    }
+
+    /// Describe this duration relative to the present moment.
+    ///
+    /// Negative durations are rendered as `"<duration> ago"` and positive ones as
+    /// `"in <duration>"`, where `<duration>` is rounded to a whole number of the unit
+    /// `Display` would use, such as `"2 hours"` or `"1 minute"`. Durations shorter
+    /// than one second in either direction are rendered as `"just now"`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::minutes(-3.0).to_relative_string(), "3 minutes ago");
+    /// assert_eq!(FloatDuration::hours(1.75).to_relative_string(), "in 2 hours");
+    /// assert_eq!(FloatDuration::seconds(70.0).to_relative_string(), "in 1 minute");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_relative_string(&self) -> String {
+        let magnitude = self.abs();
+        if magnitude.secs < 1.0 {
+            return String::from("just now");
+        }
+
+        // Rounding may reach the next unit up (59.6 minutes becomes 60), so the unit
+        // is chosen again for the rounded duration.
+        let unit = TimeUnit::display_unit(magnitude).unwrap_or(TimeUnit::Seconds);
+        let rounded = unit.duration(unit.convert(magnitude).round());
+        let unit = TimeUnit::display_unit(rounded).unwrap_or(TimeUnit::Seconds);
+        let count = unit.convert(rounded);
+        let name = unit.name();
+        let text = if count == 1.0 {
+            // Every unit name is its singular with a trailing `s`.
+            format!("1 {}", &name[..name.len() - 1])
+        } else {
+            format!("{} {}", count, name)
+        };
+
+        if self.is_negative() {
+            format!("{} ago", text)
+        } else {
+            format!("in {}", text)
+        }
+    }
+
+    /// Compute the harmonic mean of a sequence of durations.
+    ///
+    /// When durations represent periods of a rate (such as frame times), the harmonic
+    /// mean gives the period corresponding to the average rate, whereas the arithmetic
+    /// mean is biased toward the longest periods.
+    ///
+    /// Zero durations have no finite reciprocal and are skipped. Returns `None` if
+    /// the sequence contains no non-zero durations.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let periods = vec![FloatDuration::seconds(1.0), FloatDuration::seconds(3.0)];
+    /// assert_eq!(FloatDuration::harmonic_mean(periods),
+    ///            Some(FloatDuration::seconds(1.5)));
+    /// ```
+    pub fn harmonic_mean<I>(iter: I) -> Option<FloatDuration>
+        where I: IntoIterator<Item = FloatDuration>
+    {
+        let (count, reciprocal_sum) = iter.into_iter()
+            .filter(|d| !d.is_zero())
+            .fold((0usize, 0.0), |(n, sum), d| (n + 1, sum + 1.0 / d.secs));
+
+        if count == 0 {
+            None
+        } else {
+            Some(FloatDuration::seconds(count as f64 / reciprocal_sum))
+        }
+    }
+
+    /// Compute the next event time of a periodic schedule strictly after `after`.
+    ///
+    /// The schedule consists of the times `phase + k*period` for every integer `k`.
+    /// This returns the smallest such time that is strictly greater than `after`,
+    /// so if `after` lies exactly on a boundary the following boundary is returned.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let next = FloatDuration::next_aligned(FloatDuration::seconds(10.0),
+    ///                                        FloatDuration::seconds(3.0),
+    ///                                        FloatDuration::seconds(1.0));
+    /// assert_eq!(next, FloatDuration::seconds(13.0));
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if `period` is not strictly positive.
+    #[cfg(feature = "std")]
+    pub fn next_aligned(after: FloatDuration,
+                        period: FloatDuration,
+                        phase: FloatDuration)
+                        -> FloatDuration {
+        assert!(period.secs > 0.0, "next_aligned requires a positive period");
+        let k = ((after.secs - phase.secs) / period.secs).floor() + 1.0;
+        let mut next = phase.secs + k * period.secs;
+        // Rounding in the division above can land the candidate on `after` itself.
+        // Step forward until it is strictly later, unless `period` is too small to
+        // change a value of this magnitude at all.
+        while next <= after.secs {
+            let stepped = next + period.secs;
+            if stepped == next {
+                break;
+            }
+            next = stepped;
+        }
+        FloatDuration::seconds(next)
+    }
+
+    /// Create a `std::time::Duration` object from a `FloatDuration`.
+    ///
+    /// # Errors
+    /// `std::time::Duration` does not support negative values or seconds
+    /// greater than `std::u64::MAX`. This function will return
+    /// `ConversionError::Negative` or `ConversionError::Overflow` if the
+    /// `FloatDuration` value is outside of either of those bounds, or
+    /// `ConversionError::NotFinite` if it is infinite or NaN.
+    #[cfg(feature = "std")]
+    pub fn to_std(&self) -> Result<time::Duration, error::ConversionError> {
+        if !self.is_finite() {
+            Err(error::ConversionError::NotFinite)
+        } else if self.is_negative() {
+            Err(error::ConversionError::Negative)
+        } else {
+            let mut seconds = self.secs.trunc();
+            let mut nanos = (self.secs.fract() * NANOS_PER_SEC).round();
+            // Rounding may carry a full second out of the nanosecond component.
+            if nanos >= NANOS_PER_SEC {
+                seconds += 1.0;
+                nanos = 0.0;
+            }
+
+            if seconds >= u64::MAX as f64 {
+                Err(error::ConversionError::Overflow)
+            } else {
+                Ok(time::Duration::new(seconds as u64, nanos as u32))
+            }
+        }
+    }
+
+    /// Create a `std::time::Duration` object from a `FloatDuration`, saturating
+    /// at the bounds of `std::time::Duration`.
+    ///
+    /// Negative and NaN durations become `Duration::ZERO`, and durations too
+    /// large to represent (including positive infinity) become the largest
+    /// representable `Duration`.
+    #[cfg(feature = "std")]
+    pub fn to_std_saturating(&self) -> time::Duration {
+        if self.secs > 0.0 {
+            self.to_std().unwrap_or_else(|_| time::Duration::new(u64::MAX, 999_999_999))
+        } else {
+            time::Duration::ZERO
+        }
+    }
+
+    /// Convert this duration into a sign flag and a `std::time::Duration` magnitude.
+    ///
+    /// The flag is `true` if the duration is less than zero, so negative durations
+    /// can be passed to APIs that take a separate sign. The magnitude saturates as
+    /// with `to_std_saturating`, and a NaN duration becomes `(false, Duration::ZERO)`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(FloatDuration::seconds(-1.5).to_signed_std(),
+    ///            (true, Duration::from_millis(1500)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_signed_std(&self) -> (bool, time::Duration) {
+        (self.secs < 0.0, self.abs().to_std_saturating())
+    }
+
+    /// Block the current thread for this duration using `std::thread::sleep`.
+    ///
+    /// # Errors
+    /// Returns a `ConversionError` without sleeping if the duration cannot be
+    /// converted with `to_std`, i.e. if it is negative, infinite or NaN.
+    #[cfg(feature = "std")]
+    pub fn sleep(&self) -> Result<(), error::ConversionError> {
+        thread::sleep(self.to_std()?);
+        Ok(())
+    }
+
+    /// Create a `std::time::Duration` object from a `FloatDuration`, and report
+    /// whether precision was lost.
+    ///
+    /// The returned flag is `true` if the duration had sub-nanosecond detail that
+    /// was rounded away by the conversion, and `false` if the `Duration` holds
+    /// exactly the same number of nanoseconds.
+    ///
+    /// # Errors
+    /// Returns a `ConversionError` under the same conditions as `to_std`.
+    #[cfg(feature = "std")]
+    pub fn to_std_checked_precision(&self)
+                                    -> Result<(time::Duration, bool), error::ConversionError> {
+        let duration = self.to_std()?;
+        let nanos = self.secs.fract() * NANOS_PER_SEC;
+        Ok((duration, nanos != nanos.round()))
+    }
+
+    /// Create a `FloatDuration` object from a `std::time::Duration`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_std(duration: time::Duration) -> FloatDuration {
+        FloatDuration::seconds((duration.as_secs() as f64) +
+                               (duration.subsec_nanos() as f64) / NANOS_PER_SEC)
+    }
+
+    /// Compute the time elapsed between `start` and `now`, clamped to `[0, window]`.
+    ///
+    /// This is useful for measuring progress through a fixed-length phase: if `now`
+    /// precedes `start` the result is zero, and once `window` has passed the result
+    /// stays at `window`. A negative or NaN `window` holds no time at all, so the
+    /// result is zero; unlike `clamp`, this never panics.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let now = start + Duration::from_secs(90);
+    /// let window = FloatDuration::minutes(1.0);
+    ///
+    /// assert_eq!(FloatDuration::elapsed_in_window(start, now, window), window);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn elapsed_in_window(start: time::Instant,
+                             now: time::Instant,
+                             window: FloatDuration)
+                             -> FloatDuration {
+        let elapsed = now.float_duration_since(start).unwrap_or_else(|_| FloatDuration::zero());
+        if window.is_nan() || window.is_negative() {
+            return FloatDuration::zero();
+        }
+        elapsed.clamp(FloatDuration::zero(), window)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FloatDuration {
+    /// Create a `chrono::Duration` object from a `FloatDuration`.
+    ///
+    /// The duration is split into whole seconds and nanoseconds, with the
+    /// nanoseconds rounded to the nearest integer. Negative durations are
+    /// converted directly.
+    ///
+    /// # Errors
+    /// Returns `ConversionError::NotFinite` if the `FloatDuration` is infinite or
+    /// NaN, or `ConversionError::Overflow` if it is outside the range supported by
+    /// `chrono::Duration` in either direction.
+    pub fn to_chrono(&self) -> Result<chrono::Duration, error::ConversionError> {
+        if !self.is_finite() {
+            return Err(error::ConversionError::NotFinite);
+        }
+        if self.secs.abs() >= i64::MAX as f64 {
+            return Err(error::ConversionError::Overflow);
+        }
+        // Both parts carry the sign of the duration.
+        let seconds = self.secs.trunc() as i64;
+        let nanos = (self.secs.fract() * NANOS_PER_SEC).round() as i64;
+
+        chrono::Duration::try_seconds(seconds)
+            .and_then(|d| d.checked_add(&chrono::Duration::nanoseconds(nanos)))
+            .ok_or(error::ConversionError::Overflow)
+    }
+
+    /// Create a `FloatDuration` object from a `chrono::Duration`.
+    ///
+    /// The whole seconds and the sub-second nanoseconds of the duration are
+    /// combined separately, so no precision is lost beyond that of `f64` even
+    /// when the total number of nanoseconds does not fit in an `i64`.
+    ///
+    /// Equivalent to using `FloatDuration::from(duration)`
+    #[inline]
+    pub fn from_chrono(duration: chrono::Duration) -> FloatDuration {
+        FloatDuration::seconds(duration.num_seconds() as f64 +
+                               duration.subsec_nanos() as f64 / NANOS_PER_SEC)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FloatDurationVisitor;
+
+// We want to serialize a `FloatDuration` as a single f64 or string instead of a struct.
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for FloatDurationVisitor {
+    type Value = FloatDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a numeric value of seconds or a duration string")
+    }
+    fn visit_str<E>(self, value: &str) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+    fn visit_f32<E>(self, value: f32) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value as f64))
+    }
+    fn visit_f64<E>(self, value: f64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value))
+    }
+    fn visit_i32<E>(self, value: i32) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value as f64))
+    }
+    fn visit_i64<E>(self, value: i64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value as f64))
+    }
+    fn visit_u32<E>(self, value: u32) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value as f64))
+    }
+    fn visit_u64<E>(self, value: u64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(FloatDuration::seconds(value as f64))
+    }
+}
+
+/// Human-readable formats such as JSON serialize a `FloatDuration` as a string in the
+/// unit `Display` would use (e.g. `"1.5 hours"`), as long as that string parses back to
+/// exactly the same value. Otherwise the string holds the shortest decimal form of its
+/// seconds, such as `"60.593826614899996 seconds"`. Compact formats serialize it as an
+/// `f64` number of seconds.
+#[cfg(feature = "serde")]
+impl Serialize for FloatDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        if serializer.is_human_readable() {
+            let unit = if self.is_finite() {
+                TimeUnit::display_unit(*self).unwrap_or(TimeUnit::Seconds)
+            } else {
+                TimeUnit::Seconds
+            };
+            let text = format!("{} {}", unit.convert(*self), unit.name());
+            match text.parse::<FloatDuration>() {
+                Ok(parsed) if parsed.secs.to_bits() == self.secs.to_bits() => {
+                    serializer.serialize_str(&text)
+                }
+                _ => serializer.collect_str(&format_args!("{} seconds", self.secs)),
+            }
+        } else {
+            serializer.serialize_f64(self.secs)
+        }
+    }
+}
+
+/// A `FloatDuration` may be deserialized from either a number of seconds or any
+/// string accepted by its `FromStr` impl, regardless of the format.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for FloatDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FloatDurationVisitor)
+        } else {
+            deserializer.deserialize_f64(FloatDurationVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TimePoint for chrono::DateTime<Tz> {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: chrono::DateTime<Tz>) -> Result<FloatDuration, ()> {
+        let chrono_duration = self.signed_duration_since(since);
+        Ok(FloatDuration::from_chrono(chrono_duration))
+    }
+}
+#[cfg(feature = "chrono")]
+#[allow(deprecated)]
+impl<Tz: chrono::TimeZone> TimePoint for chrono::Date<Tz> {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: chrono::Date<Tz>) -> Result<FloatDuration, ()> {
+        let chrono_duration = self.signed_duration_since(since);
+        Ok(FloatDuration::from_chrono(chrono_duration))
+    }
+}
+#[cfg(feature = "chrono")]
+impl TimePoint for chrono::NaiveDate {
+    type Error = ();
+    #[inline]
+    fn float_duration_since(self, since: chrono::NaiveDate) -> Result<FloatDuration, ()> {
+        let chrono_duration = self.signed_duration_since(since);
+        Ok(FloatDuration::from_chrono(chrono_duration))
     }
 }
 #[cfg(feature = "chrono")]
@@ -409,14 +1356,39 @@ impl TimePoint for chrono::NaiveDateTime {
     }
 }
 
+#[cfg(feature = "std")]
+impl TimePointExt for time::Instant {
+    type Output = FloatDuration;
+    #[inline]
+    fn elapsed_float(&self) -> FloatDuration {
+        FloatDuration::from_std(self.elapsed())
+    }
+}
+/// Returns a `SystemTimeError` if the system clock is now earlier than `self`.
+#[cfg(feature = "std")]
+impl TimePointExt for time::SystemTime {
+    type Output = Result<FloatDuration, time::SystemTimeError>;
+    #[inline]
+    fn elapsed_float(&self) -> Result<FloatDuration, time::SystemTimeError> {
+        self.elapsed().map(FloatDuration::from_std)
+    }
+}
+
+/// Returns an `OutOfRangeError` if `since` is later than `self`, as the
+/// resulting duration would be negative.
+#[cfg(feature = "std")]
 impl TimePoint for time::Instant {
-    type Error = ();
+    type Error = error::OutOfRangeError;
     #[inline]
-    fn float_duration_since(self, since: time::Instant) -> Result<FloatDuration, ()> {
-        let std_duration = self.duration_since(since);
+    fn float_duration_since(self,
+                            since: time::Instant)
+                            -> Result<FloatDuration, error::OutOfRangeError> {
+        let std_duration = self.checked_duration_since(since)
+            .ok_or_else(error::OutOfRangeError::new)?;
         Ok(FloatDuration::from_std(std_duration))
     }
 }
+#[cfg(feature = "std")]
 impl TimePoint for time::SystemTime {
     type Error = time::SystemTimeError;
     #[inline]
@@ -428,6 +1400,46 @@ impl TimePoint for time::SystemTime {
     }
 }
 
+// Allow computing durations between borrowed time points without giving up
+// ownership of either endpoint.
+#[cfg(feature = "std")]
+macro_rules! forward_ref_time_point {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> TimePoint<$t> for &'a $t {
+                type Error = <$t as TimePoint>::Error;
+                #[inline]
+                fn float_duration_since(self,
+                                        since: $t)
+                                        -> Result<FloatDuration, Self::Error> {
+                    (*self).float_duration_since(since)
+                }
+            }
+            impl<'a> TimePoint<&'a $t> for $t {
+                type Error = <$t as TimePoint>::Error;
+                #[inline]
+                fn float_duration_since(self,
+                                        since: &'a $t)
+                                        -> Result<FloatDuration, Self::Error> {
+                    self.float_duration_since(*since)
+                }
+            }
+            impl<'a, 'b> TimePoint<&'b $t> for &'a $t {
+                type Error = <$t as TimePoint>::Error;
+                #[inline]
+                fn float_duration_since(self,
+                                        since: &'b $t)
+                                        -> Result<FloatDuration, Self::Error> {
+                    (*self).float_duration_since(*since)
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "std")]
+forward_ref_time_point!(time::Instant, time::SystemTime);
+
 impl FromDuration<time::Duration> for FloatDuration {
     type Error = ();
     #[inline]
@@ -443,24 +1455,48 @@ impl FromDuration<chrono::Duration> for FloatDuration {
         Ok(FloatDuration::from_chrono(from))
     }
 }
+#[cfg(feature = "std")]
 impl FromDuration<FloatDuration> for time::Duration {
-    type Error = error::OutOfRangeError;
+    type Error = error::ConversionError;
     #[inline]
-    fn from_duration(from: FloatDuration) -> Result<time::Duration, error::OutOfRangeError> {
+    fn from_duration(from: FloatDuration) -> Result<time::Duration, error::ConversionError> {
         from.to_std()
     }
 }
 #[cfg(feature = "chrono")]
 impl FromDuration<FloatDuration> for chrono::Duration {
-    type Error = error::OutOfRangeError;
+    type Error = error::ConversionError;
     #[inline]
-    fn from_duration(from: FloatDuration) -> Result<chrono::Duration, error::OutOfRangeError> {
+    fn from_duration(from: FloatDuration) -> Result<chrono::Duration, error::ConversionError> {
         from.to_chrono()
     }
 }
-
-impl From<time::Duration> for FloatDuration {
-    #[inline]
+/// Convert a `std::time::Duration` to a `chrono::Duration` by way of `FloatDuration`.
+///
+/// The conversion is subject to the precision of `FloatDuration`, so durations of
+/// more than a few months may lose some nanoseconds.
+#[cfg(feature = "chrono")]
+impl FromDuration<time::Duration> for chrono::Duration {
+    type Error = error::ConversionError;
+    #[inline]
+    fn from_duration(from: time::Duration) -> Result<chrono::Duration, error::ConversionError> {
+        FloatDuration::from_std(from).to_chrono()
+    }
+}
+/// Convert a `chrono::Duration` to a `std::time::Duration` by way of `FloatDuration`.
+///
+/// As with `FloatDuration::to_std`, negative durations are out of range.
+#[cfg(feature = "chrono")]
+impl FromDuration<chrono::Duration> for time::Duration {
+    type Error = error::ConversionError;
+    #[inline]
+    fn from_duration(from: chrono::Duration) -> Result<time::Duration, error::ConversionError> {
+        FloatDuration::from_chrono(from).to_std()
+    }
+}
+
+impl From<time::Duration> for FloatDuration {
+    #[inline]
     fn from(from: time::Duration) -> FloatDuration {
         FloatDuration::from_std(from)
     }
@@ -475,57 +1511,217 @@ impl From<chrono::Duration> for FloatDuration {
 
 impl fmt::Display for FloatDuration {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let decimals = fmt.precision();
-
-        match decimals {
-            Some(decimals) => {
-                if self.secs > SECS_PER_YEAR {
-                    return write!(fmt, "{:.*} years", decimals, self.as_years());
-                } else if self.secs > SECS_PER_DAY {
-                    return write!(fmt, "{:.*} days", decimals, self.as_days());
-                } else if self.secs > SECS_PER_HOUR {
-                    return write!(fmt, "{:.*} hours", decimals, self.as_hours());
-                } else if self.secs > SECS_PER_MINUTE {
-                    return write!(fmt, "{:.*} minutes", decimals, self.as_minutes());
-                } else if self.secs > 1.0 {
-                    return write!(fmt, "{:.*} seconds", decimals, self.as_seconds());
-                } else if self.secs > 1.0e-3 {
-                    return write!(fmt, "{:.*} milliseconds", decimals, self.as_milliseconds());
-                } else if self.secs > 1.0e-6 {
-                    return write!(fmt, "{:.*} microseconds", decimals, self.as_microseconds());
-                } else if self.secs > 1.0e-9 {
-                    return write!(fmt, "{:.*} nanoseconds", decimals, self.as_nanoseconds());
-                } else if self.is_zero() {
-                    return write!(fmt, "0 seconds");
-                } else {
-                    // Here we simply print seconds in scientific notation.
-                    return write!(fmt, "{:e} seconds", self.as_seconds());
-                }
+        // The unit is chosen by magnitude so negative durations use the same
+        // unit as their positive counterparts.
+        match TimeUnit::display_unit(*self) {
+            Some(unit) => {
+                // Negative zero is printed without its sign.
+                let value = if self.is_zero() { 0.0 } else { unit.convert(*self) };
+                // Formatting the value with `fmt` itself forwards the precision, width,
+                // fill and `+` sign options to the numeric portion.
+                fmt::Display::fmt(&value, fmt)?;
+                write!(fmt, " {}", unit.name())
             }
             None => {
-                if self.secs > SECS_PER_YEAR {
-                    return write!(fmt, "{} years", self.as_years());
-                } else if self.secs > SECS_PER_DAY {
-                    return write!(fmt, "{} days", self.as_days());
-                } else if self.secs > SECS_PER_HOUR {
-                    return write!(fmt, "{} hours", self.as_hours());
-                } else if self.secs > SECS_PER_MINUTE {
-                    return write!(fmt, "{} minutes", self.as_minutes());
-                } else if self.secs > 1.0 {
-                    return write!(fmt, "{} seconds", self.as_seconds());
-                } else if self.secs > 1.0e-3 {
-                    return write!(fmt, "{} milliseconds", self.as_milliseconds());
-                } else if self.secs > 1.0e-6 {
-                    return write!(fmt, "{} microseconds", self.as_microseconds());
-                } else if self.secs > 1.0e-9 {
-                    return write!(fmt, "{} nanoseconds", self.as_nanoseconds());
-                } else if self.is_zero() {
-                    return write!(fmt, "0 seconds");
-                } else {
-                    return write!(fmt, "{:e} seconds", self.as_seconds());
-                }
+                // Here we simply print seconds in scientific notation.
+                fmt::LowerExp::fmt(&self.secs, fmt)?;
+                fmt.write_str(" seconds")
+            }
+        }
+    }
+}
+
+/// Formats the number of seconds in scientific notation, without a unit suffix.
+///
+/// All formatting options, such as precision, are forwarded to the `f64` value.
+impl fmt::LowerExp for FloatDuration {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.secs, fmt)
+    }
+}
+/// Formats the number of seconds in scientific notation, without a unit suffix.
+///
+/// All formatting options, such as precision, are forwarded to the `f64` value.
+impl fmt::UpperExp for FloatDuration {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.secs, fmt)
+    }
+}
+
+/// Return the number of seconds in the unit named by `unit`, if it is recognized.
+fn seconds_per_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "y" | "year" | "years" => Some(SECS_PER_YEAR),
+        "w" | "week" | "weeks" => Some(SECS_PER_WEEK),
+        "d" | "day" | "days" => Some(SECS_PER_DAY),
+        "h" | "hour" | "hours" => Some(SECS_PER_HOUR),
+        "m" | "min" | "minute" | "minutes" => Some(SECS_PER_MINUTE),
+        "s" | "second" | "seconds" => Some(1.0),
+        "ms" | "millisecond" | "milliseconds" => Some(1.0 / MILLIS_PER_SEC),
+        "us" | "\u{b5}s" | "\u{3bc}s" | "microsecond" | "microseconds" => {
+            Some(1.0 / MICROS_PER_SEC)
+        }
+        "ns" | "nanosecond" | "nanoseconds" => Some(1.0 / NANOS_PER_SEC),
+        _ => None,
+    }
+}
+
+/// Split a string into a leading decimal number and the remainder.
+///
+/// Exponents are included in the number, so `"1.5e3ms"` splits into
+/// `("1.5e3", "ms")`.
+fn split_number(s: &str) -> (&str, &str) {
+    let bytes = s.as_bytes();
+    let mut end = 0;
+    while end < bytes.len() {
+        let c = bytes[end];
+        let after_exponent = end > 0 && (bytes[end - 1] == b'e' || bytes[end - 1] == b'E');
+        let is_sign = (c == b'+' || c == b'-') && (end == 0 || after_exponent);
+        let is_exponent = (c == b'e' || c == b'E') && end > 0 &&
+                          bytes.get(end + 1)
+            .is_some_and(|&next| next.is_ascii_digit() || next == b'+' || next == b'-');
+
+        if c.is_ascii_digit() || c == b'.' || is_sign || is_exponent {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+    (&s[..end], &s[end..])
+}
+
+/// Parse a `FloatDuration` from a number followed by a unit, such as `"3.5h"`,
+/// `"120 ms"` or `"2.5 days"`.
+///
+/// Whitespace between the number and the unit is optional. Both the short unit
+/// suffixes (`ns`, `us`/`µs`, `ms`, `s`, `m`/`min`, `h`, `d`, `w` and `y`) and the long
+/// names emitted by `Display` (`"minutes"`, `"hours"`, ...) are accepted, so
+/// parsing the output of `Display` yields approximately the original duration.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// assert_eq!("3.5h".parse::<FloatDuration>().unwrap(), FloatDuration::hours(3.5));
+/// assert_eq!("120 ms".parse::<FloatDuration>().unwrap(),
+///            FloatDuration::milliseconds(120.0));
+/// ```
+///
+/// # Errors
+/// Returns a `ParseDurationError` if the number is malformed or the unit is missing
+/// or unrecognized.
+impl FromStr for FloatDuration {
+    type Err = error::ParseDurationError;
+
+    fn from_str(s: &str) -> Result<FloatDuration, error::ParseDurationError> {
+        let s = s.trim();
+        let (number, unit) = match s.find(char::is_whitespace) {
+            Some(index) => (&s[..index], s[index..].trim_start()),
+            None => split_number(s),
+        };
+
+        let value = number.parse::<f64>().map_err(|_| error::ParseDurationError::new())?;
+        let scale = seconds_per_unit(unit).ok_or_else(error::ParseDurationError::new)?;
+        Ok(FloatDuration::seconds(value * scale))
+    }
+}
+
+/// Equivalent to `s.parse::<FloatDuration>()`.
+impl<'a> TryFrom<&'a str> for FloatDuration {
+    type Error = error::ParseDurationError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<FloatDuration, error::ParseDurationError> {
+        s.parse()
+    }
+}
+
+impl FloatDuration {
+    /// Parse a `FloatDuration` from a compound string of components, such as
+    /// `"1h 30m 15s"` or `"2d4h"`, and return their sum.
+    ///
+    /// Each component is an unsigned number followed by a unit, as accepted by the
+    /// `FromStr` impl, and whitespace between components is optional. A single
+    /// leading `-` negates the whole duration, so the output of `humanize` can be
+    /// parsed back. Parsing is lenient: units may appear in any order and may be
+    /// repeated, and every component is simply added to the total.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::parse_humanized("1h 30m 15s").unwrap(),
+    ///            FloatDuration::seconds(5415.0));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a `ParseDurationError` if the string is empty, or if any component
+    /// has a malformed number or a missing or unrecognized unit.
+    pub fn parse_humanized(s: &str) -> Result<FloatDuration, error::ParseDurationError> {
+        let s = s.trim();
+        let (sign, mut rest) = match s.strip_prefix('-') {
+            Some(rest) => (-1.0, rest.trim_start()),
+            None => (1.0, s),
+        };
+        if rest.is_empty() {
+            return Err(error::ParseDurationError::new());
+        }
+
+        let mut total = FloatDuration::zero();
+        while !rest.is_empty() {
+            if rest.starts_with(['+', '-']) {
+                return Err(error::ParseDurationError::new());
             }
+            let (number, remainder) = split_number(rest);
+            let remainder = remainder.trim_start();
+            let unit_len = remainder.find(|c: char| !c.is_alphabetic())
+                .unwrap_or(remainder.len());
+            let (unit, remainder) = remainder.split_at(unit_len);
+
+            let value = number.parse::<f64>().map_err(|_| error::ParseDurationError::new())?;
+            let scale = seconds_per_unit(unit).ok_or_else(error::ParseDurationError::new)?;
+            total += FloatDuration::seconds(value * scale);
+            rest = remainder.trim_start();
         }
+        Ok(total * sign)
+    }
+}
+
+/// Create a `FloatDuration` from a number of seconds.
+///
+/// A bare `f64` is always interpreted as seconds, exactly like
+/// `FloatDuration::seconds`, so infinite and NaN values are accepted as well.
+/// Because of this impl, the standard library provides an infallible
+/// `TryFrom<f64>`, and a `TryFrom<f64>` that rejects NaN cannot also exist; use
+/// `FloatDuration::try_seconds` to reject infinite and NaN values instead.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// let duration: FloatDuration = 1.5.into();
+/// assert_eq!(duration, FloatDuration::milliseconds(1500.0));
+/// ```
+impl From<f64> for FloatDuration {
+    #[inline]
+    fn from(secs: f64) -> FloatDuration {
+        FloatDuration::seconds(secs)
+    }
+}
+
+/// Convert a `FloatDuration` into its number of seconds.
+///
+/// This is equivalent to `as_seconds`, and is the inverse of `From<f64>`.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// let secs: f64 = FloatDuration::minutes(1.5).into();
+/// assert_eq!(secs, 90.0);
+/// ```
+impl From<FloatDuration> for f64 {
+    #[inline]
+    fn from(from: FloatDuration) -> f64 {
+        from.as_seconds()
     }
 }
 
@@ -537,6 +1733,14 @@ impl ops::Neg for FloatDuration {
         FloatDuration { secs: -self.secs }
     }
 }
+impl ops::Neg for &FloatDuration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn neg(self) -> FloatDuration {
+        -*self
+    }
+}
 
 impl ops::Add<FloatDuration> for FloatDuration {
     type Output = FloatDuration;
@@ -587,6 +1791,18 @@ impl ops::Div<FloatDuration> for FloatDuration {
         self.secs / rhs.secs
     }
 }
+/// Compute the remainder of dividing one duration by another.
+///
+/// Like `f64`'s `%` operator, this is a truncated remainder: the result has the
+/// same sign as the dividend `self`, so `-25 hours % 1 day` is `-1 hour`.
+impl ops::Rem<FloatDuration> for FloatDuration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn rem(self, rhs: FloatDuration) -> FloatDuration {
+        FloatDuration { secs: self.secs % rhs.secs }
+    }
+}
 
 impl ops::AddAssign<FloatDuration> for FloatDuration {
     #[inline]
@@ -613,12 +1829,166 @@ impl ops::DivAssign<f64> for FloatDuration {
         self.secs /= rhs;
     }
 }
+impl ops::RemAssign<FloatDuration> for FloatDuration {
+    #[inline]
+    fn rem_assign(&mut self, rhs: FloatDuration) {
+        self.secs %= rhs.secs;
+    }
+}
+
+impl ops::Add<time::Duration> for FloatDuration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn add(self, rhs: time::Duration) -> FloatDuration {
+        self + FloatDuration::from_std(rhs)
+    }
+}
+impl ops::Sub<time::Duration> for FloatDuration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn sub(self, rhs: time::Duration) -> FloatDuration {
+        self - FloatDuration::from_std(rhs)
+    }
+}
+impl ops::Add<FloatDuration> for time::Duration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn add(self, rhs: FloatDuration) -> FloatDuration {
+        FloatDuration::from_std(self) + rhs
+    }
+}
+impl ops::Sub<FloatDuration> for time::Duration {
+    type Output = FloatDuration;
+
+    #[inline]
+    fn sub(self, rhs: FloatDuration) -> FloatDuration {
+        FloatDuration::from_std(self) - rhs
+    }
+}
+impl ops::AddAssign<time::Duration> for FloatDuration {
+    #[inline]
+    fn add_assign(&mut self, rhs: time::Duration) {
+        *self += FloatDuration::from_std(rhs);
+    }
+}
+impl ops::SubAssign<time::Duration> for FloatDuration {
+    #[inline]
+    fn sub_assign(&mut self, rhs: time::Duration) {
+        *self -= FloatDuration::from_std(rhs);
+    }
+}
+
+// Implement scaling by an integer type by converting it to `f64`.
+macro_rules! impl_integer_scalar_ops {
+    ($($t:ty),*) => {
+        $(
+            impl ops::Mul<$t> for FloatDuration {
+                type Output = FloatDuration;
+
+                #[inline]
+                fn mul(self, rhs: $t) -> FloatDuration {
+                    FloatDuration { secs: self.secs * rhs as f64 }
+                }
+            }
+            impl ops::Mul<FloatDuration> for $t {
+                type Output = FloatDuration;
+
+                #[inline]
+                fn mul(self, rhs: FloatDuration) -> FloatDuration {
+                    FloatDuration { secs: self as f64 * rhs.secs }
+                }
+            }
+            impl ops::Div<$t> for FloatDuration {
+                type Output = FloatDuration;
+
+                #[inline]
+                fn div(self, rhs: $t) -> FloatDuration {
+                    FloatDuration { secs: self.secs / rhs as f64 }
+                }
+            }
+            impl ops::MulAssign<$t> for FloatDuration {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $t) {
+                    self.secs *= rhs as f64;
+                }
+            }
+            impl ops::DivAssign<$t> for FloatDuration {
+                #[inline]
+                fn div_assign(&mut self, rhs: $t) {
+                    self.secs /= rhs as f64;
+                }
+            }
+        )*
+    }
+}
+
+impl_integer_scalar_ops!(i32, u32);
+
+// Implement the reference forms of a binary operator (`&a op b`, `a op &b` and
+// `&a op &b`) in terms of its by-value implementation.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a> ops::$imp<$u> for &'a $t {
+            type Output = <$t as ops::$imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, rhs: $u) -> <$t as ops::$imp<$u>>::Output {
+                ops::$imp::$method(*self, rhs)
+            }
+        }
+        impl<'a> ops::$imp<&'a $u> for $t {
+            type Output = <$t as ops::$imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, rhs: &'a $u) -> <$t as ops::$imp<$u>>::Output {
+                ops::$imp::$method(self, *rhs)
+            }
+        }
+        impl<'a, 'b> ops::$imp<&'a $u> for &'b $t {
+            type Output = <$t as ops::$imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, rhs: &'a $u) -> <$t as ops::$imp<$u>>::Output {
+                ops::$imp::$method(*self, *rhs)
+            }
+        }
+    }
+}
+
+forward_ref_binop!(impl Add, add for FloatDuration, FloatDuration);
+forward_ref_binop!(impl Sub, sub for FloatDuration, FloatDuration);
+forward_ref_binop!(impl Mul, mul for FloatDuration, f64);
+forward_ref_binop!(impl Mul, mul for f64, FloatDuration);
+forward_ref_binop!(impl Div, div for FloatDuration, f64);
+forward_ref_binop!(impl Div, div for FloatDuration, FloatDuration);
+forward_ref_binop!(impl Rem, rem for FloatDuration, FloatDuration);
+
 impl Default for FloatDuration {
     #[inline]
     fn default() -> FloatDuration {
         FloatDuration::zero()
     }
 }
+/// Durations are hashed by the bit pattern of their underlying `f64`.
+///
+/// This upholds the `Hash`/`PartialEq` contract for all values except signed
+/// zeros and NaN: `+0.0` and `-0.0` compare equal but hash differently, and a
+/// NaN duration is never equal to anything, including itself. Use
+/// `FiniteFloatDuration` for keys that may be zero of either sign.
+impl Hash for FloatDuration {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.secs.to_bits().hash(state);
+    }
+}
+/// Equality is reflexive for every duration except NaN, see `Hash`.
+impl Eq for FloatDuration {}
+/// Summing an iterator of `Result<FloatDuration, E>` into a `Result<FloatDuration, E>`
+/// is also supported through the standard library's blanket implementation, which
+/// stops at the first `Err`.
 impl Sum for FloatDuration {
     fn sum<I>(iter: I) -> FloatDuration
         where I: Iterator<Item = FloatDuration>
@@ -658,111 +2028,933 @@ impl ApproxEq for FloatDuration {
     fn ulps_eq(&self, other: &FloatDuration, epsilon: f64, max_ulps: u32) -> bool {
         self.secs.ulps_eq(&other.secs, epsilon, max_ulps)
     }
-}
+}
+
+/// A `FloatDuration` that is known to be finite.
+///
+/// Unlike `FloatDuration`, `FiniteFloatDuration` implements `Ord`, so it can be
+/// sorted directly and used as a key of a `BTreeMap` or `BTreeSet`. Negative zero
+/// is stored as positive zero, so equality, ordering and hashing all agree.
+///
+/// ```rust
+/// use float_duration::{FloatDuration, FiniteFloatDuration};
+///
+/// let mut durations = vec![FiniteFloatDuration::new(FloatDuration::hours(1.0)).unwrap(),
+///                          FiniteFloatDuration::new(FloatDuration::seconds(5.0)).unwrap()];
+/// durations.sort();
+/// assert_eq!(durations[0].get(), FloatDuration::seconds(5.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiniteFloatDuration(FloatDuration);
+
+impl FiniteFloatDuration {
+    /// Wrap `duration`, returning `None` if it is infinite or NaN.
+    #[inline]
+    pub fn new(duration: FloatDuration) -> Option<FiniteFloatDuration> {
+        if duration.is_finite() {
+            // Adding zero turns -0.0 into +0.0 and leaves every other value unchanged.
+            Some(FiniteFloatDuration(FloatDuration { secs: duration.secs + 0.0 }))
+        } else {
+            None
+        }
+    }
+    /// Return the wrapped `FloatDuration`.
+    #[inline]
+    pub fn get(&self) -> FloatDuration {
+        self.0
+    }
+}
+
+impl Ord for FiniteFloatDuration {
+    #[inline]
+    fn cmp(&self, other: &FiniteFloatDuration) -> cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+impl PartialOrd for FiniteFloatDuration {
+    #[inline]
+    fn partial_cmp(&self, other: &FiniteFloatDuration) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl From<FiniteFloatDuration> for FloatDuration {
+    #[inline]
+    fn from(from: FiniteFloatDuration) -> FloatDuration {
+        from.0
+    }
+}
+
+/// The length of a duration expressed in each unit, as returned by
+/// `FloatDuration::as_all_units`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AllUnits {
+    /// The number of fractional years.
+    pub years: f64,
+    /// The number of fractional days.
+    pub days: f64,
+    /// The number of fractional hours.
+    pub hours: f64,
+    /// The number of fractional minutes.
+    pub minutes: f64,
+    /// The number of fractional seconds.
+    pub seconds: f64,
+    /// The number of fractional milliseconds.
+    pub millis: f64,
+    /// The number of fractional microseconds.
+    pub micros: f64,
+    /// The number of fractional nanoseconds.
+    pub nanos: f64,
+}
+
+/// A builder for composing a `FloatDuration` from integer parts.
+///
+/// Each method *adds* its amount to the duration being built rather than
+/// replacing a previous value, so `.hours(1).hours(2)` yields three hours.
+/// Negative amounts are subtracted.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+///
+/// let duration = FloatDuration::builder().days(1).hours(2).minutes(30).build();
+/// assert_eq!(duration, FloatDuration::hours(26.5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FloatDurationBuilder {
+    secs: f64,
+}
+
+impl FloatDurationBuilder {
+    /// Create a new builder for a zero duration.
+    #[inline]
+    pub fn new() -> FloatDurationBuilder {
+        FloatDurationBuilder::default()
+    }
+    /// Add `years` years to the duration being built.
+    #[inline]
+    pub fn years(mut self, years: i64) -> FloatDurationBuilder {
+        self.secs += years as f64 * SECS_PER_YEAR;
+        self
+    }
+    /// Add `weeks` weeks to the duration being built.
+    #[inline]
+    pub fn weeks(mut self, weeks: i64) -> FloatDurationBuilder {
+        self.secs += weeks as f64 * SECS_PER_WEEK;
+        self
+    }
+    /// Add `days` days to the duration being built.
+    #[inline]
+    pub fn days(mut self, days: i64) -> FloatDurationBuilder {
+        self.secs += days as f64 * SECS_PER_DAY;
+        self
+    }
+    /// Add `hours` hours to the duration being built.
+    #[inline]
+    pub fn hours(mut self, hours: i64) -> FloatDurationBuilder {
+        self.secs += hours as f64 * SECS_PER_HOUR;
+        self
+    }
+    /// Add `minutes` minutes to the duration being built.
+    #[inline]
+    pub fn minutes(mut self, minutes: i64) -> FloatDurationBuilder {
+        self.secs += minutes as f64 * SECS_PER_MINUTE;
+        self
+    }
+    /// Add `seconds` seconds to the duration being built.
+    #[inline]
+    pub fn seconds(mut self, seconds: i64) -> FloatDurationBuilder {
+        self.secs += seconds as f64;
+        self
+    }
+    /// Add `millis` milliseconds to the duration being built.
+    #[inline]
+    pub fn milliseconds(mut self, millis: i64) -> FloatDurationBuilder {
+        self.secs += millis as f64 / MILLIS_PER_SEC;
+        self
+    }
+    /// Add `micros` microseconds to the duration being built.
+    #[inline]
+    pub fn microseconds(mut self, micros: i64) -> FloatDurationBuilder {
+        self.secs += micros as f64 / MICROS_PER_SEC;
+        self
+    }
+    /// Add `nanos` nanoseconds to the duration being built.
+    #[inline]
+    pub fn nanoseconds(mut self, nanos: i64) -> FloatDurationBuilder {
+        self.secs += nanos as f64 / NANOS_PER_SEC;
+        self
+    }
+
+    /// Return the accumulated `FloatDuration`.
+    #[inline]
+    pub fn build(&self) -> FloatDuration {
+        FloatDuration::seconds(self.secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{format, vec};
+    use std::vec::Vec;
+    use std::time;
+
+    #[test]
+    fn test_construct() {
+        let duration1 = FloatDuration::hours(3.0);
+        assert_eq!(duration1.as_hours(), 3.0);
+        assert_eq!(duration1.as_minutes(), 180.0);
+        assert_eq!(duration1.as_seconds(), 180.0 * 60.0);
+        assert_eq!(duration1.as_days(), 3.0 / 24.0);
+        assert_eq!(duration1.as_milliseconds(), 180.0 * 60.0 * 1000.0);
+        assert!(duration1.is_positive());
+        assert_eq!(duration1.signum(), 1.0);
+
+        let duration2 = FloatDuration::milliseconds(55.0);
+        assert_eq!(duration2.as_seconds(), 0.055);
+        assert_eq!(duration2.as_milliseconds(), 55.0);
+        assert_eq!(duration2.as_microseconds(), 55000.0);
+        assert_eq!(duration2.as_nanoseconds(), 55000000.0);
+        assert!(!duration2.is_zero());
+
+        let duration3 = FloatDuration::zero();
+        assert!(duration3.is_zero());
+        assert_eq!(duration3.as_minutes(), 0.0);
+        assert_eq!(duration3.as_nanoseconds(), 0.0);
+
+        assert_eq!(FloatDuration::days(1.5), FloatDuration::hours(36.0));
+        assert_eq!(FloatDuration::minutes(30.0), FloatDuration::hours(0.5));
+        assert_eq!(FloatDuration::seconds(180.0), FloatDuration::minutes(3.0));
+        assert_eq!(FloatDuration::seconds(3.5),
+                   FloatDuration::milliseconds(3500.0));
+        assert_eq!(FloatDuration::microseconds(300.0),
+                   FloatDuration::milliseconds(0.30));
+        assert_eq!(FloatDuration::nanoseconds(1000.0),
+                   FloatDuration::microseconds(1.0));
+
+        let duration4 = FloatDuration::minutes(-3.0);
+        assert_eq!(duration4.as_minutes(), -3.0);
+        assert_eq!(duration4.as_hours(), -0.05);
+        assert!(duration4.is_negative());
+        assert_eq!(duration4.signum(), -1.0);
+
+        assert_eq!(FloatDuration::years(2.0), FloatDuration::days(365.0 * 2.0));
+
+        let duration5 = FloatDuration::weeks(2.0);
+        assert_eq!(duration5.as_weeks(), 2.0);
+        assert_eq!(duration5.as_days(), 14.0);
+        assert_eq!(duration5, FloatDuration::days(14.0));
+        assert_eq!(FloatDuration::days(3.5).as_weeks(), 0.5);
+        assert_eq!(FloatDuration::weeks(1.0).as_seconds(), SECS_PER_WEEK);
+    }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!(FloatDuration::seconds(2.5).signum(), 1.0);
+        assert_eq!(FloatDuration::days(-1.0).signum(), -1.0);
+        assert_eq!(FloatDuration::zero().signum(), 1.0);
+        assert_eq!((-FloatDuration::zero()).signum(), -1.0);
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).signum(), 1.0);
+        assert_eq!(FloatDuration::seconds(f64::NEG_INFINITY).signum(), -1.0);
+        assert!(FloatDuration::seconds(f64::NAN).signum().is_nan());
+    }
+
+    #[test]
+    fn test_builder() {
+        assert_eq!(FloatDuration::builder().build(), FloatDuration::zero());
+        assert_eq!(FloatDuration::builder().days(1).hours(2).minutes(30).build(),
+                   FloatDuration::days(1.0) + FloatDuration::hours(2.0) +
+                   FloatDuration::minutes(30.0));
+        assert_eq!(FloatDuration::builder().hours(1).hours(2).build(),
+                   FloatDuration::hours(3.0));
+        assert_eq!(FloatDuration::builder().minutes(2).seconds(-30).build(),
+                   FloatDuration::seconds(90.0));
+        assert_eq!(FloatDuration::builder().years(1).weeks(2).build(),
+                   FloatDuration::days(365.0 + 14.0));
+        assert_eq!(FloatDuration::builder().seconds(1).milliseconds(250).build(),
+                   FloatDuration::seconds(1.25));
+        assert_eq!(FloatDuration::builder().microseconds(500).nanoseconds(500).build(),
+                   FloatDuration::nanoseconds(500_500.0));
+
+        let builder = FloatDurationBuilder::new().minutes(1);
+        assert_eq!(builder.build(), FloatDuration::minutes(1.0));
+        assert_eq!(builder.seconds(15).build(), FloatDuration::seconds(75.0));
+    }
+
+    #[test]
+    fn test_as_all_units() {
+        let durations = [FloatDuration::hours(3.0),
+                         FloatDuration::milliseconds(-55.0),
+                         FloatDuration::years(1.25),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            let units = duration.as_all_units();
+            assert_eq!(units.years, duration.as_years());
+            assert_eq!(units.days, duration.as_days());
+            assert_eq!(units.hours, duration.as_hours());
+            assert_eq!(units.minutes, duration.as_minutes());
+            assert_eq!(units.seconds, duration.as_seconds());
+            assert_eq!(units.millis, duration.as_milliseconds());
+            assert_eq!(units.micros, duration.as_microseconds());
+            assert_eq!(units.nanos, duration.as_nanoseconds());
+        }
+
+        assert_eq!(FloatDuration::zero().as_all_units(), AllUnits::default());
+    }
+
+    #[test]
+    fn test_copysign() {
+        let a = FloatDuration::seconds(2.5);
+        let b = FloatDuration::seconds(-2.5);
+
+        assert_eq!(a.copysign(FloatDuration::minutes(-1.0)), b);
+        assert_eq!(b.copysign(FloatDuration::minutes(1.0)), a);
+        assert_eq!(a.copysign(FloatDuration::hours(3.0)), a);
+        assert_eq!(b.copysign(FloatDuration::hours(-3.0)), b);
+
+        assert_eq!(a.copysign(FloatDuration::zero()), a);
+        assert_eq!(a.copysign(-FloatDuration::zero()), b);
+        assert!(FloatDuration::zero().copysign(b).is_negative());
+        assert!(!(-FloatDuration::zero()).copysign(a).is_negative());
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).copysign(b),
+                   FloatDuration::seconds(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_finiteness() {
+        let finite = FloatDuration::hours(3.0);
+        let inf = FloatDuration::seconds(f64::INFINITY);
+        let neg_inf = FloatDuration::seconds(f64::NEG_INFINITY);
+        let nan = FloatDuration::seconds(f64::NAN);
+
+        assert!(finite.is_finite());
+        assert!(!finite.is_infinite());
+        assert!(!finite.is_nan());
+        assert!(FloatDuration::zero().is_finite());
+        assert!(FloatDuration::max_value().is_finite());
+
+        assert!(!inf.is_finite());
+        assert!(inf.is_infinite());
+        assert!(!inf.is_nan());
+        assert!(!neg_inf.is_finite());
+        assert!(neg_inf.is_infinite());
+        assert!(!neg_inf.is_nan());
+        assert!((FloatDuration::seconds(10.0) / 0.0).is_infinite());
+
+        assert!(!nan.is_finite());
+        assert!(!nan.is_infinite());
+        assert!(nan.is_nan());
+        assert!((inf - inf).is_nan());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(FloatDuration::minutes(5.0) + FloatDuration::seconds(30.0),
+                   FloatDuration::seconds(330.0));
+
+        assert_eq!(FloatDuration::hours(3.0) * 2.5, FloatDuration::hours(7.5));
+
+        assert_eq!(FloatDuration::days(3.0) / 3.0 - FloatDuration::hours(2.0),
+                   FloatDuration::hours(22.0));
+
+        assert_eq!(FloatDuration::zero() + FloatDuration::milliseconds(500.0) +
+                   FloatDuration::microseconds(500.0),
+                   FloatDuration::microseconds(500500.0));
+
+        assert_eq!(2.0 * FloatDuration::milliseconds(150.0),
+                   FloatDuration::milliseconds(300.0));
+
+        assert_eq!(FloatDuration::minutes(10.0) / FloatDuration::seconds(60.0),
+                   10.0);
+        assert_eq!(FloatDuration::minutes(5.0),
+                   (-FloatDuration::minutes(5.0)) * -1.0);
+
+        assert_eq!(FloatDuration::seconds(10.0) - FloatDuration::minutes(1.0),
+                   FloatDuration::seconds(-50.0));
+
+        let inf = FloatDuration::seconds(10.0) / 0.0;
+
+        assert!(inf.as_seconds().is_infinite());
+        assert!(inf.as_years().is_infinite());
+        assert!(inf.as_microseconds().is_infinite());
+        assert!(FloatDuration::hours(10.0) / FloatDuration::minutes(0.0) == f64::INFINITY);
+
+        let mut d1 = FloatDuration::seconds(5.0);
+        d1 += FloatDuration::minutes(10.0);
+        d1 *= 2.0;
+        assert_eq!(d1,
+                   FloatDuration::minutes(20.0) + FloatDuration::seconds(10.0));
+        d1 /= 2.0;
+        assert_eq!(d1,
+                   FloatDuration::minutes(10.0) + FloatDuration::seconds(5.0));
+        d1 -= d1;
+        assert_eq!(d1, FloatDuration::zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rounding() {
+        let minute = FloatDuration::minutes(1.0);
+
+        assert_eq!(FloatDuration::seconds(95.0).round_to(minute),
+                   FloatDuration::minutes(2.0));
+        assert_eq!(FloatDuration::seconds(85.0).round_to(minute),
+                   FloatDuration::minutes(1.0));
+        assert_eq!(FloatDuration::seconds(90.0).round_to(minute),
+                   FloatDuration::minutes(2.0));
+        assert_eq!(FloatDuration::seconds(-90.0).round_to(minute),
+                   FloatDuration::minutes(-2.0));
+        assert_eq!(FloatDuration::seconds(-85.0).round_to(minute),
+                   FloatDuration::minutes(-1.0));
+
+        assert_eq!(FloatDuration::seconds(119.0).floor_to(minute),
+                   FloatDuration::minutes(1.0));
+        assert_eq!(FloatDuration::seconds(-61.0).floor_to(minute),
+                   FloatDuration::minutes(-2.0));
+        assert_eq!(FloatDuration::seconds(61.0).ceil_to(minute),
+                   FloatDuration::minutes(2.0));
+        assert_eq!(FloatDuration::seconds(-119.0).ceil_to(minute),
+                   FloatDuration::minutes(-1.0));
+
+        assert_eq!(FloatDuration::hours(2.0).round_to(minute), FloatDuration::hours(2.0));
+        assert_eq!(FloatDuration::hours(2.0).floor_to(minute), FloatDuration::hours(2.0));
+        assert_eq!(FloatDuration::hours(2.0).ceil_to(minute), FloatDuration::hours(2.0));
+
+        assert_eq!(FloatDuration::seconds(52.0).round_to(FloatDuration::seconds(-15.0)),
+                   FloatDuration::seconds(45.0));
+        assert_eq!(FloatDuration::seconds(12.3).round_to(FloatDuration::zero()),
+                   FloatDuration::seconds(12.3));
+        assert_eq!(FloatDuration::seconds(12.3).floor_to(FloatDuration::zero()),
+                   FloatDuration::seconds(12.3));
+    }
+
+    #[test]
+    fn test_integer_arithmetic() {
+        assert_eq!(FloatDuration::hours(3.0) * 2, FloatDuration::hours(6.0));
+        assert_eq!(FloatDuration::hours(3.0) * -2, FloatDuration::hours(-6.0));
+        assert_eq!(FloatDuration::hours(3.0) * 2u32, FloatDuration::hours(6.0));
+        assert_eq!(3 * FloatDuration::seconds(1.5), FloatDuration::seconds(4.5));
+        assert_eq!(3u32 * FloatDuration::seconds(1.5), FloatDuration::seconds(4.5));
+        assert_eq!(FloatDuration::minutes(10.0) / 2, FloatDuration::minutes(5.0));
+        assert_eq!(FloatDuration::minutes(10.0) / 4u32, FloatDuration::minutes(2.5));
+        assert!((FloatDuration::minutes(10.0) / 0i32).is_infinite());
+
+        let mut d = FloatDuration::seconds(5.0);
+        d *= 4;
+        assert_eq!(d, FloatDuration::seconds(20.0));
+        d /= 8u32;
+        assert_eq!(d, FloatDuration::seconds(2.5));
+        d *= 2u32;
+        d /= -5;
+        assert_eq!(d, FloatDuration::seconds(-1.0));
+    }
+
+    #[test]
+    fn test_reference_arithmetic() {
+        fn sum_refs<T>(values: &[T]) -> T
+            where T: Default,
+                  for<'a> &'a T: ops::Add<&'a T, Output = T>
+        {
+            values.iter().fold(T::default(), |acc, x| &acc + x)
+        }
+
+        let a = FloatDuration::minutes(5.0);
+        let b = FloatDuration::seconds(30.0);
+        let (ra, rb) = (&a, &b);
+        let two = 2.0;
+        let r_two = &two;
+
+        assert_eq!(ra + rb, a + b);
+        assert_eq!(ra + b, a + b);
+        assert_eq!(a + rb, a + b);
+        assert_eq!(ra - rb, FloatDuration::seconds(270.0));
+        assert_eq!(ra - b, a - b);
+        assert_eq!(a - rb, a - b);
+
+        assert_eq!(ra * 2.0, FloatDuration::minutes(10.0));
+        assert_eq!(a * r_two, FloatDuration::minutes(10.0));
+        assert_eq!(r_two * ra, FloatDuration::minutes(10.0));
+        assert_eq!(ra / 5.0, FloatDuration::minutes(1.0));
+        assert_eq!(ra / rb, 10.0);
+        assert_eq!(ra % FloatDuration::minutes(2.0), FloatDuration::minutes(1.0));
+
+        assert_eq!(sum_refs(&[a, b, FloatDuration::seconds(15.0)]),
+                   FloatDuration::seconds(345.0));
+    }
+
+    #[test]
+    fn test_reference_negation() {
+        let a = FloatDuration::seconds(1.5);
+        let ra = &a;
+        assert_eq!(-ra, FloatDuration::seconds(-1.5));
+        assert_eq!(-(-ra), a);
+
+        let negated: Vec<FloatDuration> = [a, FloatDuration::minutes(-2.0)]
+            .iter()
+            .map(|d| -d)
+            .collect();
+        assert_eq!(negated, vec![FloatDuration::seconds(-1.5), FloatDuration::minutes(2.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_wrap_to() {
+        let day = FloatDuration::days(1.0);
+
+        assert_eq!(FloatDuration::hours(5.0).wrap_to(day), FloatDuration::hours(5.0));
+        assert_eq!(FloatDuration::hours(30.0).wrap_to(day), FloatDuration::hours(6.0));
+        assert_eq!(FloatDuration::hours(-1.0).wrap_to(day), FloatDuration::hours(23.0));
+        assert_eq!(FloatDuration::hours(-49.0).wrap_to(day), FloatDuration::hours(23.0));
+        assert_eq!(FloatDuration::hours(48.0).wrap_to(day), FloatDuration::zero());
+        assert_eq!(FloatDuration::hours(-24.0).wrap_to(day), FloatDuration::zero());
+        assert_eq!(FloatDuration::hours(-1.0).wrap_to(-day), FloatDuration::hours(23.0));
+        assert_eq!(FloatDuration::seconds(-1e-20).wrap_to(day), FloatDuration::zero());
+        assert!(FloatDuration::hours(1.0).wrap_to(FloatDuration::zero()).is_nan());
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(FloatDuration::hours(25.0) % FloatDuration::days(1.0),
+                   FloatDuration::hours(1.0));
+        assert_eq!(FloatDuration::seconds(150.0) % FloatDuration::minutes(1.0),
+                   FloatDuration::seconds(30.0));
+        assert_eq!(FloatDuration::hours(48.0) % FloatDuration::days(1.0),
+                   FloatDuration::zero());
+        assert_eq!(FloatDuration::seconds(30.0) % FloatDuration::minutes(1.0),
+                   FloatDuration::seconds(30.0));
+
+        assert_eq!(FloatDuration::hours(-25.0) % FloatDuration::days(1.0),
+                   FloatDuration::hours(-1.0));
+        assert_eq!(FloatDuration::hours(25.0) % FloatDuration::days(-1.0),
+                   FloatDuration::hours(1.0));
+        assert!((FloatDuration::hours(1.0) % FloatDuration::zero()).as_seconds().is_nan());
+
+        let mut d = FloatDuration::minutes(90.0);
+        d %= FloatDuration::hours(1.0);
+        assert_eq!(d, FloatDuration::minutes(30.0));
+    }
+
+    #[test]
+    fn test_try_constructors() {
+        assert_eq!(FloatDuration::try_years(2.0), Ok(FloatDuration::years(2.0)));
+        assert_eq!(FloatDuration::try_days(-1.5), Ok(FloatDuration::days(-1.5)));
+        assert_eq!(FloatDuration::try_minutes(0.0), Ok(FloatDuration::zero()));
+
+        // Roughly the largest number of years whose product with `SECS_PER_YEAR`
+        // is finite.
+        let max_years = f64::MAX / SECS_PER_YEAR;
+        assert!(FloatDuration::try_years(max_years * 0.9999).is_ok());
+        assert!(FloatDuration::try_years(max_years * 1.0001).is_err());
+        assert!(FloatDuration::try_years(-max_years * 1.0001).is_err());
+        assert_eq!(FloatDuration::try_years(1e308), Err(error::OutOfRangeError::new()));
+
+        let max_hours = f64::MAX / SECS_PER_HOUR;
+        assert!(FloatDuration::try_hours(max_hours * 0.9999).is_ok());
+        assert!(FloatDuration::try_hours(max_hours * 1.0001).is_err());
+        assert!(FloatDuration::try_centuries(1e300).is_err());
+        assert!(FloatDuration::try_decades(f64::INFINITY).is_err());
+        assert!(FloatDuration::try_weeks(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let max = FloatDuration::max_value();
+        let min = FloatDuration::min_value();
+
+        assert_eq!(FloatDuration::seconds(1.5).saturating_add(FloatDuration::seconds(2.0)),
+                   FloatDuration::seconds(3.5));
+        assert_eq!(FloatDuration::seconds(1.5).saturating_sub(FloatDuration::seconds(2.0)),
+                   FloatDuration::seconds(-0.5));
+
+        assert!((max + max).is_infinite());
+        assert_eq!(max.saturating_add(max), max);
+        assert_eq!(max.saturating_sub(min), max);
+        assert_eq!(min.saturating_add(min), min);
+        assert_eq!(min.saturating_sub(max), min);
+        assert_eq!(max.saturating_add(min), FloatDuration::zero());
+        assert!(max.saturating_add(max).is_finite());
+
+        let nan = FloatDuration::seconds(f64::NAN);
+        assert!(nan.saturating_add(FloatDuration::seconds(1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(FloatDuration::minutes(5.0).checked_add(FloatDuration::seconds(30.0)),
+                   Some(FloatDuration::seconds(330.0)));
+        assert_eq!(FloatDuration::seconds(10.0).checked_sub(FloatDuration::minutes(1.0)),
+                   Some(FloatDuration::seconds(-50.0)));
+        assert_eq!(FloatDuration::hours(3.0).checked_mul(2.5),
+                   Some(FloatDuration::hours(7.5)));
+        assert_eq!(FloatDuration::days(3.0).checked_div(3.0),
+                   Some(FloatDuration::days(1.0)));
+
+        assert_eq!(FloatDuration::seconds(10.0).checked_div(0.0), None);
+        assert_eq!(FloatDuration::zero().checked_div(0.0), None);
+        assert_eq!(FloatDuration::max_value().checked_add(FloatDuration::max_value()),
+                   None);
+        assert_eq!(FloatDuration::min_value().checked_sub(FloatDuration::max_value()),
+                   None);
+        assert_eq!(FloatDuration::max_value().checked_mul(2.0), None);
+        assert_eq!(FloatDuration::seconds(1.0).checked_mul(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(FloatDuration::minutes(5.0).max(FloatDuration::minutes(10.0)),
+                   FloatDuration::minutes(10.0));
+        assert_eq!(FloatDuration::minutes(5.0).min(FloatDuration::minutes(10.0)),
+                   FloatDuration::minutes(5.0));
+        assert_eq!(FloatDuration::zero().max(FloatDuration::seconds(1.0)),
+                   FloatDuration::seconds(1.0));
+        assert_eq!(FloatDuration::zero().min(FloatDuration::seconds(1.0)),
+                   FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_total_cmp() {
+        use std::cmp::Ordering;
+
+        assert_eq!(FloatDuration::minutes(1.0).total_cmp(&FloatDuration::seconds(60.0)),
+                   Ordering::Equal);
+        assert_eq!(FloatDuration::zero().total_cmp(&FloatDuration::seconds(1.0)),
+                   Ordering::Less);
+        assert_eq!(FloatDuration::seconds(-1.0).total_cmp(&FloatDuration::zero()),
+                   Ordering::Less);
+        assert_eq!((-FloatDuration::zero()).total_cmp(&FloatDuration::zero()),
+                   Ordering::Less);
+
+        let nan = FloatDuration::seconds(f64::NAN);
+        let mut durations = [FloatDuration::hours(1.0),
+                             nan,
+                             FloatDuration::seconds(-5.0),
+                             FloatDuration::zero(),
+                             FloatDuration::max_value(),
+                             FloatDuration::minutes(-2.0)];
+        durations.sort_by(FloatDuration::total_cmp);
+
+        assert_eq!(&durations[..5],
+                   &[FloatDuration::minutes(-2.0),
+                     FloatDuration::seconds(-5.0),
+                     FloatDuration::zero(),
+                     FloatDuration::hours(1.0),
+                     FloatDuration::max_value()]);
+        assert!(durations[5].as_seconds().is_nan());
+    }
+
+    #[test]
+    fn test_decades_centuries() {
+        assert_eq!(FloatDuration::centuries(1.0), FloatDuration::years(100.0));
+        assert_eq!(FloatDuration::decades(1.0), FloatDuration::years(10.0));
+        assert_eq!(FloatDuration::centuries(1.0), FloatDuration::decades(10.0));
+        assert_eq!(FloatDuration::years(250.0).as_centuries(), 2.5);
+        assert_eq!(FloatDuration::years(250.0).as_decades(), 25.0);
+        assert_eq!(FloatDuration::centuries(3.0).as_years(), 300.0);
+        assert_eq!(FloatDuration::decades(1.0).as_days(), 3650.0);
+    }
+
+    #[test]
+    fn test_const_construct() {
+        const TIMEOUT: FloatDuration = FloatDuration::seconds(30.0);
+        const FRAME: FloatDuration = FloatDuration::milliseconds(16.0);
+        const SHIFT: FloatDuration = FloatDuration::from_hms(8, 30, 0.0);
+        static DELAYS: [FloatDuration; 3] = [FloatDuration::zero(),
+                                             FloatDuration::minutes(1.5),
+                                             FloatDuration::days(2.0)];
+
+        assert_eq!(TIMEOUT, FloatDuration::seconds(30.0));
+        assert_eq!(TIMEOUT * 2.0, FloatDuration::minutes(1.0));
+        assert_eq!(FRAME.as_milliseconds(), 16.0);
+        assert_eq!(SHIFT, FloatDuration::hours(8.5));
+        assert_eq!(DELAYS[1], FloatDuration::seconds(90.0));
+        assert_eq!(DELAYS[2].as_hours(), 48.0);
+    }
+
+    #[test]
+    fn test_from_hms() {
+        assert_eq!(FloatDuration::from_hms(1, 30, 0.0), FloatDuration::hours(1.5));
+        assert_eq!(FloatDuration::from_hms(0, 0, 12.5), FloatDuration::seconds(12.5));
+        assert_eq!(FloatDuration::from_hms(0, 90, 0.0), FloatDuration::minutes(90.0));
+        assert_eq!(FloatDuration::from_hms(2, 3, 4.0), FloatDuration::seconds(7384.0));
+
+        assert_eq!(FloatDuration::from_hms_milli(1, 30, 0, 0), FloatDuration::hours(1.5));
+        assert_eq!(FloatDuration::from_hms_milli(0, 1, 2, 500),
+                   FloatDuration::seconds(62.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_split_seconds() {
+        assert_eq!(FloatDuration::seconds(90.25).split_seconds(), (90, 0.25));
+        assert_eq!(FloatDuration::seconds(-1.25).split_seconds(), (-1, -0.25));
+        assert_eq!(FloatDuration::seconds(-0.5).split_seconds(), (0, -0.5));
+        assert_eq!(FloatDuration::minutes(2.0).split_seconds(), (120, 0.0));
+        assert_eq!(FloatDuration::zero().split_seconds(), (0, 0.0));
+
+        let durations = [FloatDuration::seconds(12.75),
+                         FloatDuration::seconds(-3.5),
+                         FloatDuration::hours(-2.0),
+                         FloatDuration::milliseconds(1.0)];
+        for &duration in durations.iter() {
+            let (whole, fraction) = duration.split_seconds();
+            assert_eq!(FloatDuration::seconds(whole as f64 + fraction), duration);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_secs_nanos() {
+        assert_eq!(FloatDuration::seconds(1.5).as_secs_nanos(), (1, 500_000_000));
+        assert_eq!(FloatDuration::seconds(-1.25).as_secs_nanos(), (-1, -250_000_000));
+        assert_eq!(FloatDuration::nanoseconds(-250.0).as_secs_nanos(), (0, -250));
+        assert_eq!(FloatDuration::seconds(2.9999999999).as_secs_nanos(), (3, 0));
+        assert_eq!(FloatDuration::seconds(-2.9999999999).as_secs_nanos(), (-3, 0));
+        assert_eq!(FloatDuration::from_secs_nanos(-1, -250_000_000),
+                   FloatDuration::seconds(-1.25));
+        assert_eq!(FloatDuration::from_secs_nanos(2, 0), FloatDuration::seconds(2.0));
+
+        let durations = [FloatDuration::seconds(12.75),
+                         FloatDuration::seconds(-3.5),
+                         FloatDuration::hours(-2.0),
+                         FloatDuration::milliseconds(-0.5),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            let (secs, nanos) = duration.as_secs_nanos();
+            assert_eq!(FloatDuration::from_secs_nanos(secs, nanos), duration);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_timespec() {
+        assert_eq!(FloatDuration::seconds(1.5).to_timespec(), (1, 500_000_000));
+        assert_eq!(FloatDuration::seconds(-1.25).to_timespec(), (-2, 750_000_000));
+        assert_eq!(FloatDuration::seconds(-2.0).to_timespec(), (-2, 0));
+        assert_eq!(FloatDuration::nanoseconds(-250.0).to_timespec(), (-1, 999_999_750));
+        assert_eq!(FloatDuration::zero().to_timespec(), (0, 0));
+        assert_eq!(FloatDuration::seconds(2.9999999999).to_timespec(), (3, 0));
+        assert_eq!(FloatDuration::seconds(-1e-12).to_timespec(), (0, 0));
+        assert_eq!(FloatDuration::from_timespec(-2, 750_000_000),
+                   FloatDuration::seconds(-1.25));
+
+        let durations = [FloatDuration::seconds(12.75),
+                         FloatDuration::seconds(-3.5),
+                         FloatDuration::hours(-2.0),
+                         FloatDuration::seconds(-0.25),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            let (sec, nsec) = duration.to_timespec();
+            assert!((0..1_000_000_000).contains(&nsec));
+            assert_eq!(FloatDuration::from_timespec(sec, nsec), duration);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_nanoseconds_i128() {
+        assert_eq!(FloatDuration::seconds(1.5).as_nanoseconds_i128(),
+                   Some(1_500_000_000));
+        assert_eq!(FloatDuration::seconds(-2.0).as_nanoseconds_i128(),
+                   Some(-2_000_000_000));
+        assert_eq!(FloatDuration::nanoseconds(0.6).as_nanoseconds_i128(), Some(1));
+        assert_eq!(FloatDuration::zero().as_nanoseconds_i128(), Some(0));
+        assert_eq!(FloatDuration::years(1e6).as_nanoseconds_i128(),
+                   Some(31_536_000_000_000_000_000_000));
+
+        assert_eq!(FloatDuration::seconds(1e30).as_nanoseconds_i128(), None);
+        assert_eq!(FloatDuration::seconds(-1e30).as_nanoseconds_i128(), None);
+        assert_eq!(FloatDuration::max_value().as_nanoseconds_i128(), None);
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).as_nanoseconds_i128(), None);
+        assert_eq!(FloatDuration::seconds(f64::NAN).as_nanoseconds_i128(), None);
+    }
+
+    #[test]
+    fn test_frames() {
+        assert_eq!(FloatDuration::from_frames(60.0, 30.0), FloatDuration::seconds(2.0));
+        assert_eq!(FloatDuration::seconds(2.0).as_frames(30.0), 60.0);
+        assert_eq!(FloatDuration::from_frames(90.0, 60.0).as_frames(60.0), 90.0);
+        assert!(FloatDuration::from_frames(1.0, 0.0).is_infinite());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_quantize_to_fps() {
+        assert_eq!(FloatDuration::seconds(1.017).quantize_to_fps(30.0),
+                   FloatDuration::from_frames(31.0, 30.0));
+        assert_eq!(FloatDuration::seconds(1.0).quantize_to_fps(24.0),
+                   FloatDuration::seconds(1.0));
+        assert_eq!(FloatDuration::milliseconds(50.0).quantize_to_fps(24.0),
+                   FloatDuration::from_frames(1.0, 24.0));
+        assert_eq!(FloatDuration::milliseconds(10.0).quantize_to_fps(24.0),
+                   FloatDuration::zero());
+        assert_eq!(FloatDuration::seconds(2.01).quantize_to_fps(60.0),
+                   FloatDuration::from_frames(121.0, 60.0));
+        assert_eq!(FloatDuration::seconds(-0.1).quantize_to_fps(60.0),
+                   FloatDuration::from_frames(-6.0, 60.0));
+        assert!(FloatDuration::seconds(1.0).quantize_to_fps(0.0).is_nan());
+    }
+
+    #[test]
+    fn test_beats() {
+        assert_eq!(FloatDuration::from_beats(1.0, 120.0), FloatDuration::seconds(0.5));
+        assert_eq!(FloatDuration::from_beats(4.0, 120.0), FloatDuration::seconds(2.0));
+        assert_eq!(FloatDuration::seconds(2.0).as_beats(120.0), 4.0);
+        assert_eq!(FloatDuration::from_beats(6.0, 90.0).as_beats(90.0), 6.0);
+    }
+
+    #[test]
+    fn test_rate() {
+        let megabyte = 1024.0 * 1024.0;
+        assert_eq!(FloatDuration::seconds(2.0).rate(10.0 * megabyte), 5.0 * megabyte);
+        assert_eq!(FloatDuration::milliseconds(250.0).rate(100.0), 400.0);
+        assert_eq!(FloatDuration::minutes(1.0).rate(0.0), 0.0);
+        assert_eq!(FloatDuration::zero().rate(10.0), f64::INFINITY);
+        assert!(FloatDuration::zero().rate(0.0).is_nan());
+
+        assert_eq!(FloatDuration::time_for(10.0 * megabyte, 5.0 * megabyte),
+                   FloatDuration::seconds(2.0));
+        assert_eq!(FloatDuration::time_for(100.0, 400.0), FloatDuration::milliseconds(250.0));
+        assert!(FloatDuration::time_for(1.0, 0.0).is_infinite());
+
+        let elapsed = FloatDuration::seconds(8.0);
+        assert_eq!(FloatDuration::time_for(3000.0, elapsed.rate(3000.0)), elapsed);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::time;
+    #[test]
+    fn test_abs_diff() {
+        let measured = FloatDuration::seconds(2.5);
+        let expected = FloatDuration::seconds(2.0);
+
+        assert_eq!(measured.abs_diff(expected), FloatDuration::seconds(0.5));
+        assert_eq!(expected.abs_diff(measured), measured.abs_diff(expected));
+        assert_eq!(FloatDuration::seconds(-3.0).abs_diff(FloatDuration::seconds(2.0)),
+                   FloatDuration::seconds(5.0));
+        assert_eq!(FloatDuration::seconds(2.0).abs_diff(FloatDuration::seconds(-3.0)),
+                   FloatDuration::seconds(5.0));
+        assert_eq!(expected.abs_diff(expected), FloatDuration::zero());
+    }
 
     #[test]
-    fn test_construct() {
-        let duration1 = FloatDuration::hours(3.0);
-        assert_eq!(duration1.as_hours(), 3.0);
-        assert_eq!(duration1.as_minutes(), 180.0);
-        assert_eq!(duration1.as_seconds(), 180.0 * 60.0);
-        assert_eq!(duration1.as_days(), 3.0 / 24.0);
-        assert_eq!(duration1.as_milliseconds(), 180.0 * 60.0 * 1000.0);
-        assert!(duration1.is_positive());
-        assert_eq!(duration1.signum(), 1.0);
+    fn test_within() {
+        let epsilon = FloatDuration::seconds(0.25);
+        let duration = FloatDuration::seconds(2.0);
+
+        let cases = [(1.5, false, false, true),
+                     (1.75, true, false, false),
+                     (1.875, true, false, false),
+                     (2.0, true, false, false),
+                     (2.125, true, false, false),
+                     (2.25, true, false, false),
+                     (2.5, false, true, false)];
+        for &(secs, eq, lt, gt) in cases.iter() {
+            let other = FloatDuration::seconds(secs);
+            assert_eq!(duration.eq_within(other, epsilon), eq);
+            assert_eq!(duration.lt_within(other, epsilon), lt);
+            assert_eq!(duration.gt_within(other, epsilon), gt);
+        }
 
-        let duration2 = FloatDuration::milliseconds(55.0);
-        assert_eq!(duration2.as_seconds(), 0.055);
-        assert_eq!(duration2.as_milliseconds(), 55.0);
-        assert_eq!(duration2.as_microseconds(), 55000.0);
-        assert_eq!(duration2.as_nanoseconds(), 55000000.0);
-        assert!(!duration2.is_zero());
+        let sum = FloatDuration::seconds(0.1) + FloatDuration::seconds(0.2);
+        let expected = FloatDuration::seconds(0.3);
+        let noise = FloatDuration::nanoseconds(1.0);
+        assert!(sum > expected);
+        assert!(sum.eq_within(expected, noise));
+        assert!(!sum.gt_within(expected, noise));
+        assert!(!expected.lt_within(sum, noise));
 
-        let duration3 = FloatDuration::zero();
-        assert!(duration3.is_zero());
-        assert_eq!(duration3.as_minutes(), 0.0);
-        assert_eq!(duration3.as_nanoseconds(), 0.0);
+        let nan = FloatDuration::seconds(f64::NAN);
+        assert!(!nan.eq_within(duration, epsilon));
+        assert!(!nan.lt_within(duration, epsilon));
+        assert!(!nan.gt_within(duration, epsilon));
+    }
 
-        assert_eq!(FloatDuration::days(1.5), FloatDuration::hours(36.0));
-        assert_eq!(FloatDuration::minutes(30.0), FloatDuration::hours(0.5));
-        assert_eq!(FloatDuration::seconds(180.0), FloatDuration::minutes(3.0));
-        assert_eq!(FloatDuration::seconds(3.5),
-                   FloatDuration::milliseconds(3500.0));
-        assert_eq!(FloatDuration::microseconds(300.0),
-                   FloatDuration::milliseconds(0.30));
-        assert_eq!(FloatDuration::nanoseconds(1000.0),
-                   FloatDuration::microseconds(1.0));
+    #[test]
+    fn test_approx_eq() {
+        let tolerance = FloatDuration::milliseconds(1.0);
+        let duration = FloatDuration::seconds(2.0);
 
-        let duration4 = FloatDuration::minutes(-3.0);
-        assert_eq!(duration4.as_minutes(), -3.0);
-        assert_eq!(duration4.as_hours(), -0.05);
-        assert!(duration4.is_negative());
-        assert_eq!(duration4.signum(), -1.0);
+        assert!(duration.approx_eq(&duration, FloatDuration::zero()));
+        assert!(duration.approx_eq(&FloatDuration::seconds(2.0005), tolerance));
+        assert!(duration.approx_eq(&FloatDuration::seconds(1.9995), tolerance));
+        assert!(!duration.approx_eq(&FloatDuration::seconds(2.002), tolerance));
+        assert!(!duration.approx_eq(&FloatDuration::seconds(1.998), tolerance));
+        assert!(!duration.approx_eq(&FloatDuration::seconds(f64::NAN), tolerance));
 
-        assert_eq!(FloatDuration::years(2.0), FloatDuration::days(365.0 * 2.0));
+        let sum = FloatDuration::seconds(0.1) + FloatDuration::seconds(0.2);
+        assert!(sum != FloatDuration::seconds(0.3));
+        assert!(sum.approx_eq(&FloatDuration::seconds(0.3), FloatDuration::nanoseconds(1.0)));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_arithmetic() {
-        assert_eq!(FloatDuration::minutes(5.0) + FloatDuration::seconds(30.0),
-                   FloatDuration::seconds(330.0));
+    fn test_mul_add() {
+        let dt = FloatDuration::milliseconds(500.0);
+        let start = FloatDuration::seconds(10.0);
 
-        assert_eq!(FloatDuration::hours(3.0) * 2.5, FloatDuration::hours(7.5));
+        assert_eq!(dt.mul_add(4.0, start), dt * 4.0 + start);
+        assert_eq!(FloatDuration::seconds(-2.0).mul_add(0.25, start),
+                   FloatDuration::seconds(9.5));
 
-        assert_eq!(FloatDuration::days(3.0) / 3.0 - FloatDuration::hours(2.0),
-                   FloatDuration::hours(22.0));
+        // 0.1 * 10.0 rounds to exactly 1.0, so the unfused result is zero. The fused
+        // operation keeps the rounding error of 0.1.
+        let tenth = FloatDuration::seconds(0.1);
+        let one = FloatDuration::seconds(1.0);
+        assert_eq!(tenth * 10.0 - one, FloatDuration::zero());
+        assert_eq!(tenth.mul_add(10.0, -one), FloatDuration::seconds(0.1f64.mul_add(10.0, -1.0)));
+        assert!(tenth.mul_add(10.0, -one).is_positive());
+    }
 
-        assert_eq!(FloatDuration::zero() + FloatDuration::milliseconds(500.0) +
-                   FloatDuration::microseconds(500.0),
-                   FloatDuration::microseconds(500500.0));
+    #[test]
+    fn test_ratio() {
+        let timeout = FloatDuration::seconds(40.0);
 
-        assert_eq!(2.0 * FloatDuration::milliseconds(150.0),
-                   FloatDuration::milliseconds(300.0));
+        assert_eq!(FloatDuration::seconds(20.0).ratio_to(timeout), 0.5);
+        assert_eq!(FloatDuration::seconds(20.0).percent_of(timeout), 50.0);
+        assert_eq!(timeout.ratio_to(timeout), 1.0);
+        assert_eq!(timeout.percent_of(timeout), 100.0);
+        assert_eq!(FloatDuration::seconds(10.0).percent_of(timeout), 25.0);
+        assert_eq!(FloatDuration::seconds(60.0).percent_of(timeout), 150.0);
 
-        assert_eq!(FloatDuration::minutes(10.0) / FloatDuration::seconds(60.0),
-                   10.0);
-        assert_eq!(FloatDuration::minutes(5.0),
-                   (-FloatDuration::minutes(5.0)) * -1.0);
+        assert_eq!(FloatDuration::seconds(1.0).ratio_to(FloatDuration::zero()),
+                   f64::INFINITY);
+        assert_eq!(FloatDuration::seconds(-1.0).percent_of(FloatDuration::zero()),
+                   f64::NEG_INFINITY);
+        assert!(FloatDuration::zero().ratio_to(FloatDuration::zero()).is_nan());
+    }
 
-        assert_eq!(FloatDuration::seconds(10.0) - FloatDuration::minutes(1.0),
-                   FloatDuration::seconds(-50.0));
+    #[test]
+    fn test_lerp() {
+        let start = FloatDuration::seconds(2.0);
+        let end = FloatDuration::seconds(6.0);
 
-        let inf = FloatDuration::seconds(10.0) / 0.0;
+        assert_eq!(start.lerp(end, 0.0), start);
+        assert_eq!(start.lerp(end, 0.5), FloatDuration::seconds(4.0));
+        assert_eq!(start.lerp(end, 1.0), end);
+        assert_eq!(start.lerp(end, 2.0), FloatDuration::seconds(10.0));
+        assert_eq!(end.lerp(start, 0.25), FloatDuration::seconds(5.0));
+    }
 
-        assert!(inf.as_seconds().is_infinite());
-        assert!(inf.as_years().is_infinite());
-        assert!(inf.as_microseconds().is_infinite());
-        assert!(FloatDuration::hours(10.0) / FloatDuration::minutes(0.0) == f64::INFINITY);
+    #[test]
+    fn test_clamp() {
+        let min = FloatDuration::milliseconds(1.0);
+        let max = FloatDuration::milliseconds(50.0);
 
-        let mut d1 = FloatDuration::seconds(5.0);
-        d1 += FloatDuration::minutes(10.0);
-        d1 *= 2.0;
-        assert_eq!(d1,
-                   FloatDuration::minutes(20.0) + FloatDuration::seconds(10.0));
-        d1 /= 2.0;
-        assert_eq!(d1,
-                   FloatDuration::minutes(10.0) + FloatDuration::seconds(5.0));
-        d1 -= d1;
-        assert_eq!(d1, FloatDuration::zero());
+        assert_eq!(FloatDuration::milliseconds(16.0).clamp(min, max),
+                   FloatDuration::milliseconds(16.0));
+        assert_eq!(FloatDuration::microseconds(10.0).clamp(min, max), min);
+        assert_eq!(FloatDuration::seconds(-1.0).clamp(min, max), min);
+        assert_eq!(FloatDuration::seconds(2.0).clamp(min, max), max);
+        assert_eq!(max.clamp(min, max), max);
+
+        assert_eq!(FloatDuration::seconds(2.0).clamp(min, min), min);
+        assert_eq!(FloatDuration::zero().clamp(max, max), max);
     }
 
+    #[should_panic]
     #[test]
-    fn test_min_max() {
-        assert_eq!(FloatDuration::minutes(5.0).max(FloatDuration::minutes(10.0)),
-                   FloatDuration::minutes(10.0));
-        assert_eq!(FloatDuration::minutes(5.0).min(FloatDuration::minutes(10.0)),
-                   FloatDuration::minutes(5.0));
-        assert_eq!(FloatDuration::zero().max(FloatDuration::seconds(1.0)),
-                   FloatDuration::seconds(1.0));
-        assert_eq!(FloatDuration::zero().min(FloatDuration::seconds(1.0)),
-                   FloatDuration::zero());
+    fn test_clamp_panic() {
+        FloatDuration::zero().clamp(FloatDuration::seconds(2.0), FloatDuration::seconds(1.0));
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_std_conversion() {
         let duration1 = FloatDuration::minutes(5.0);
@@ -775,7 +2967,7 @@ mod tests {
         let duration2 = FloatDuration::hours(-2.0);
         assert!(duration2.is_negative());
         assert_eq!(duration2.signum(), -1.0);
-        assert!(!duration2.to_std().is_ok());
+        assert!(duration2.to_std().is_err());
         let std_duration2 = (-duration2).to_std().unwrap();
         assert_eq!(std_duration2, time::Duration::new(3600 * 2, 0));
         assert_eq!(FloatDuration::from(std_duration2), -duration2);
@@ -787,6 +2979,10 @@ mod tests {
         assert!(FloatDuration::zero().is_positive());
         assert!(FloatDuration::nanoseconds(-1.0).to_std().is_err());
         assert!(FloatDuration::max_value().to_std().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_std().is_err());
+        assert!((-FloatDuration::seconds(f64::NAN)).to_std().is_err());
+        assert!(FloatDuration::seconds(f64::INFINITY).to_std().is_err());
+        assert!(FloatDuration::seconds(f64::NEG_INFINITY).to_std().is_err());
 
         assert_eq!(FloatDuration::from_std(time::Duration::new(0, 1)),
                    FloatDuration::nanoseconds(1.0));
@@ -796,6 +2992,125 @@ mod tests {
                    FloatDuration::seconds(1.0) + FloatDuration::nanoseconds(1.0));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_conversion_error() {
+        use error::ConversionError;
+
+        assert_eq!(FloatDuration::seconds(-1.0).to_std(), Err(ConversionError::Negative));
+        assert_eq!(FloatDuration::seconds(1e20).to_std(), Err(ConversionError::Overflow));
+        assert_eq!(FloatDuration::max_value().to_std(), Err(ConversionError::Overflow));
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_std(),
+                   Err(ConversionError::NotFinite));
+        assert_eq!(FloatDuration::seconds(f64::NEG_INFINITY).to_std(),
+                   Err(ConversionError::NotFinite));
+
+        // The specific reason may still be discarded into an `OutOfRangeError`.
+        let error: error::OutOfRangeError = ConversionError::Negative.into();
+        assert_eq!(error, error::OutOfRangeError::new());
+        assert_eq!(ConversionError::Overflow.to_string(),
+                   "The converted duration value is too large.");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_saturating() {
+        let max = time::Duration::new(u64::MAX, 999_999_999);
+
+        assert_eq!(FloatDuration::seconds(1.5).to_std_saturating(),
+                   time::Duration::new(1, 500_000_000));
+        assert_eq!(FloatDuration::zero().to_std_saturating(), time::Duration::ZERO);
+        assert_eq!(FloatDuration::seconds(-1.5).to_std_saturating(), time::Duration::ZERO);
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_std_saturating(),
+                   time::Duration::ZERO);
+        assert_eq!(FloatDuration::seconds(1e20).to_std_saturating(), max);
+        assert_eq!(FloatDuration::max_value().to_std_saturating(), max);
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).to_std_saturating(), max);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_signed_std() {
+        assert_eq!(FloatDuration::seconds(1.5).to_signed_std(),
+                   (false, time::Duration::new(1, 500_000_000)));
+        assert_eq!(FloatDuration::seconds(-1.5).to_signed_std(),
+                   (true, time::Duration::new(1, 500_000_000)));
+        assert_eq!(FloatDuration::minutes(-2.0).to_signed_std(),
+                   (true, time::Duration::from_secs(120)));
+        assert_eq!(FloatDuration::zero().to_signed_std(), (false, time::Duration::ZERO));
+        assert_eq!((-FloatDuration::zero()).to_signed_std(), (false, time::Duration::ZERO));
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_signed_std(),
+                   (false, time::Duration::ZERO));
+        assert_eq!(FloatDuration::seconds(f64::NEG_INFINITY).to_signed_std(),
+                   (true, time::Duration::new(u64::MAX, 999_999_999)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_checked_precision() {
+        assert_eq!(FloatDuration::seconds(1.5).to_std_checked_precision(),
+                   Ok((time::Duration::new(1, 500_000_000), false)));
+        assert_eq!(FloatDuration::nanoseconds(250.0).to_std_checked_precision(),
+                   Ok((time::Duration::new(0, 250), false)));
+        assert_eq!(FloatDuration::zero().to_std_checked_precision(),
+                   Ok((time::Duration::ZERO, false)));
+
+        assert_eq!(FloatDuration::nanoseconds(0.25).to_std_checked_precision(),
+                   Ok((time::Duration::ZERO, true)));
+        assert_eq!(FloatDuration::nanoseconds(2.5).to_std_checked_precision(),
+                   Ok((time::Duration::new(0, 3), true)));
+        assert_eq!((FloatDuration::seconds(3.0) + FloatDuration::nanoseconds(0.75))
+                       .to_std_checked_precision(),
+                   Ok((time::Duration::new(3, 1), true)));
+
+        assert!(FloatDuration::seconds(-1.0).to_std_checked_precision().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_std_checked_precision().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_conversion_rounding() {
+        assert_eq!(FloatDuration::seconds(0.1).to_std().unwrap(),
+                   time::Duration::new(0, 100_000_000));
+        assert_eq!(FloatDuration::seconds(1.9999999999).to_std().unwrap(),
+                   time::Duration::new(2, 0));
+        assert_eq!(FloatDuration::seconds(1.9999999994).to_std().unwrap(),
+                   time::Duration::new(1, 999_999_999));
+        assert_eq!(FloatDuration::nanoseconds(0.4).to_std().unwrap(),
+                   time::Duration::new(0, 0));
+        assert_eq!(FloatDuration::nanoseconds(0.6).to_std().unwrap(),
+                   time::Duration::new(0, 1));
+
+        let std_durations = [time::Duration::new(1, 999_999_999),
+                             time::Duration::new(0, 1),
+                             time::Duration::new(12345, 678_901_234),
+                             time::Duration::new(3600, 0)];
+        for &std_duration in std_durations.iter() {
+            assert_eq!(FloatDuration::from_std(std_duration).to_std().unwrap(),
+                       std_duration);
+        }
+    }
+
+    #[test]
+    fn test_std_arithmetic() {
+        let float_duration = FloatDuration::minutes(5.0);
+        let std_duration = time::Duration::from_millis(1500);
+        let converted = FloatDuration::from_std(std_duration);
+
+        assert_eq!(float_duration + std_duration, float_duration + converted);
+        assert_eq!(float_duration + std_duration, FloatDuration::seconds(301.5));
+        assert_eq!(float_duration - std_duration, float_duration - converted);
+        assert_eq!(std_duration + float_duration, converted + float_duration);
+        assert_eq!(std_duration - float_duration, FloatDuration::seconds(-298.5));
+
+        let mut d = FloatDuration::zero();
+        d += time::Duration::new(2, 500_000_000);
+        assert_eq!(d, FloatDuration::seconds(2.5));
+        d -= time::Duration::new(5, 0);
+        assert_eq!(d, FloatDuration::seconds(-2.5));
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_time_point_std() {
         use std::time::{Instant, SystemTime, UNIX_EPOCH};
@@ -811,6 +3126,105 @@ mod tests {
                 FloatDuration::years(30.0));
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sleep() {
+        use std::time::Instant;
+
+        let start = Instant::now();
+        assert_eq!(FloatDuration::milliseconds(2.0).sleep(), Ok(()));
+        assert!(start.elapsed_float() >= FloatDuration::milliseconds(2.0));
+
+        let start = Instant::now();
+        assert!(FloatDuration::seconds(-10.0).sleep().is_err());
+        assert!(FloatDuration::seconds(f64::INFINITY).sleep().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).sleep().is_err());
+        assert!(start.elapsed_float() < FloatDuration::seconds(1.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_elapsed_float() {
+        use std::thread;
+        use std::time::{Duration, Instant, SystemTime};
+
+        let instant = Instant::now();
+        let system = SystemTime::now();
+        thread::sleep(Duration::from_millis(5));
+
+        let elapsed = instant.elapsed_float();
+        assert!(elapsed >= FloatDuration::milliseconds(5.0));
+        assert!(instant.elapsed_float() >= elapsed);
+        assert!(system.elapsed_float().unwrap() > FloatDuration::zero());
+        assert!((system + Duration::from_secs(3600)).elapsed_float().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_time_point_instant_order() {
+        use std::time::{Duration, Instant};
+
+        let earlier = Instant::now();
+        let later = earlier + Duration::from_secs(1);
+        assert_eq!(later.float_duration_since(earlier), Ok(FloatDuration::seconds(1.0)));
+        assert_eq!(earlier.float_duration_since(later), Err(error::OutOfRangeError::new()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_time_point_std_ref() {
+        use std::time::{Duration, Instant, SystemTime};
+
+        let start = Instant::now();
+        let end = start + Duration::from_millis(1500);
+        assert_eq!((&end).float_duration_since(&start), Ok(FloatDuration::seconds(1.5)));
+        assert_eq!((&end).float_duration_since(start), Ok(FloatDuration::seconds(1.5)));
+        assert_eq!(end.float_duration_since(&start), Ok(FloatDuration::seconds(1.5)));
+        // Both endpoints are still available after computing the duration.
+        assert!(end > start);
+        assert!((&start).float_duration_since(&end).is_err());
+
+        let sys_start = SystemTime::now();
+        let sys_end = sys_start + Duration::from_secs(2);
+        assert_eq!((&sys_end).float_duration_since(&sys_start).unwrap(),
+                   FloatDuration::seconds(2.0));
+        assert!((&sys_start).float_duration_since(&sys_end).is_err());
+        assert!(sys_end > sys_start);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_elapsed_in_window() {
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now() + Duration::from_secs(10);
+        let window = FloatDuration::seconds(60.0);
+
+        let before = start - Duration::from_secs(5);
+        assert_eq!(FloatDuration::elapsed_in_window(start, before, window),
+                   FloatDuration::zero());
+        assert_eq!(FloatDuration::elapsed_in_window(start, start, window),
+                   FloatDuration::zero());
+
+        let mid = start + Duration::from_millis(30500);
+        assert_eq!(FloatDuration::elapsed_in_window(start, mid, window),
+                   FloatDuration::seconds(30.5));
+
+        let after = start + Duration::from_secs(120);
+        assert_eq!(FloatDuration::elapsed_in_window(start, after, window), window);
+
+        assert_eq!(FloatDuration::elapsed_in_window(start, mid, FloatDuration::seconds(-1.0)),
+                   FloatDuration::zero());
+        assert_eq!(FloatDuration::elapsed_in_window(start,
+                                                    mid,
+                                                    FloatDuration::seconds(f64::NAN)),
+                   FloatDuration::zero());
+        assert_eq!(FloatDuration::elapsed_in_window(start,
+                                                    before,
+                                                    FloatDuration::seconds(-1.0)),
+                   FloatDuration::zero());
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", FloatDuration::minutes(3.5)), "3.5 minutes");
@@ -838,6 +3252,290 @@ mod tests {
                    "1.5e-30 seconds");
     }
 
+    #[test]
+    fn test_exp_format() {
+        let duration = FloatDuration::seconds(150.0);
+        assert_eq!(format!("{:e}", duration), "1.5e2");
+        assert_eq!(format!("{:E}", duration), "1.5E2");
+        assert_eq!(format!("{:.3e}", duration), format!("{:.3e}", 150.0));
+        assert_eq!(format!("{:.2E}", FloatDuration::milliseconds(-1.0)), "-1.00E-3");
+
+        for &secs in [0.0, 1e-12, 42.0, -3.25, 6.02e23].iter() {
+            assert_eq!(format!("{:e}", FloatDuration::seconds(secs)), format!("{:e}", secs));
+            assert_eq!(format!("{:E}", FloatDuration::seconds(secs)), format!("{:E}", secs));
+        }
+    }
+
+    #[test]
+    fn test_display_negative() {
+        assert_eq!(format!("{}", FloatDuration::minutes(-3.5)), "-3.5 minutes");
+        assert_eq!(format!("{}", FloatDuration::hours(-2.0)), "-2 hours");
+        assert_eq!(format!("{}", FloatDuration::days(-3.5)), "-3.5 days");
+        assert_eq!(format!("{}", FloatDuration::years(-1.0)), "-1 years");
+        assert_eq!(format!("{}", FloatDuration::seconds(-12.7)), "-12.7 seconds");
+        assert_eq!(format!("{}", FloatDuration::milliseconds(-12.5)),
+                   "-12.5 milliseconds");
+        assert_eq!(format!("{}", FloatDuration::microseconds(-100.0)),
+                   "-100 microseconds");
+        assert_eq!(format!("{}", FloatDuration::nanoseconds(-25.25)),
+                   "-25.25 nanoseconds");
+        assert_eq!(format!("{}", FloatDuration::seconds(-1.5e-30)),
+                   "-1.5e-30 seconds");
+        assert_eq!(format!("{}", -FloatDuration::zero()), "0 seconds");
+        assert_eq!(format!("{:.2}", FloatDuration::minutes(-3.5)), "-3.50 minutes");
+    }
+
+    #[test]
+    fn test_display_format_options() {
+        assert_eq!(format!("{:.3}", FloatDuration::seconds(12.6789)), "12.679 seconds");
+        assert_eq!(format!("{:.1}", FloatDuration::minutes(90.0)), "1.5 hours");
+        assert_eq!(format!("{:.2}", FloatDuration::milliseconds(12.5)),
+                   "12.50 milliseconds");
+        assert_eq!(format!("{:.0}", FloatDuration::days(3.25)), "3 days");
+        assert_eq!(format!("{:.2}", FloatDuration::zero()), "0.00 seconds");
+        assert_eq!(format!("{:.2}", FloatDuration::seconds(1.5e-30)),
+                   "1.50e-30 seconds");
+
+        assert_eq!(format!("{:6}", FloatDuration::minutes(3.5)), "   3.5 minutes");
+        assert_eq!(format!("{:<6}", FloatDuration::minutes(3.5)), "3.5    minutes");
+        assert_eq!(format!("{:*>8.2}", FloatDuration::hours(2.0)), "****2.00 hours");
+
+        assert_eq!(format!("{}", FloatDuration::seconds(12.6789)), "12.6789 seconds");
+        assert_eq!(format!("{}", FloatDuration::zero()), "0 seconds");
+    }
+
+    #[test]
+    fn test_display_sign_plus() {
+        assert_eq!(format!("{:+}", FloatDuration::minutes(5.0)), "+5 minutes");
+        assert_eq!(format!("{:+}", FloatDuration::minutes(-5.0)), "-5 minutes");
+        assert_eq!(format!("{}", FloatDuration::minutes(5.0)), "5 minutes");
+        assert_eq!(format!("{}", FloatDuration::minutes(-5.0)), "-5 minutes");
+
+        assert_eq!(format!("{:+.1}", FloatDuration::hours(1.5)), "+1.5 hours");
+        assert_eq!(format!("{:+.1}", FloatDuration::hours(-1.5)), "-1.5 hours");
+        assert_eq!(format!("{:+}", FloatDuration::zero()), "+0 seconds");
+        assert_eq!(format!("{:+}", -FloatDuration::zero()), "+0 seconds");
+        assert_eq!(format!("{:+}", FloatDuration::seconds(1.5e-30)), "+1.5e-30 seconds");
+        assert_eq!(format!("{:+}", FloatDuration::seconds(-1.5e-30)), "-1.5e-30 seconds");
+    }
+
+    #[test]
+    fn test_display_boundaries() {
+        assert_eq!(format!("{}", FloatDuration::years(1.0)), "1 years");
+        assert_eq!(format!("{}", FloatDuration::days(1.0)), "1 days");
+        assert_eq!(format!("{}", FloatDuration::hours(1.0)), "1 hours");
+        assert_eq!(format!("{}", FloatDuration::minutes(1.0)), "1 minutes");
+        assert_eq!(format!("{}", FloatDuration::seconds(SECS_PER_MINUTE)), "1 minutes");
+        assert_eq!(format!("{}", FloatDuration::seconds(1.0)), "1 seconds");
+        assert_eq!(format!("{}", FloatDuration::milliseconds(1.0)), "1 milliseconds");
+        assert_eq!(format!("{}", FloatDuration::microseconds(1.0)), "1 microseconds");
+        assert_eq!(format!("{}", FloatDuration::nanoseconds(1.0)), "1 nanoseconds");
+
+        assert_eq!(format!("{:.1}", FloatDuration::hours(1.0)), "1.0 hours");
+        assert_eq!(format!("{:.1}", FloatDuration::seconds(1.0)), "1.0 seconds");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_relative_string() {
+        assert_eq!(FloatDuration::minutes(3.0).to_relative_string(),
+                   "in 3 minutes");
+        assert_eq!(FloatDuration::days(2.5).to_relative_string(), "in 3 days");
+        assert_eq!(FloatDuration::days(7.0).to_relative_string(), "in 7 days");
+        assert_eq!(FloatDuration::seconds(89.0).to_relative_string(), "in 1 minute");
+        assert_eq!(FloatDuration::minutes(59.6).to_relative_string(), "in 1 hour");
+        assert_eq!(FloatDuration::seconds(-1.4).to_relative_string(), "1 second ago");
+        assert_eq!(FloatDuration::hours(-2.0).to_relative_string(), "2 hours ago");
+        assert_eq!(FloatDuration::seconds(-45.0).to_relative_string(),
+                   "45 seconds ago");
+
+        assert_eq!(FloatDuration::seconds(1.0).to_relative_string(), "in 1 second");
+        assert_eq!(FloatDuration::minutes(1.0).to_relative_string(), "in 1 minute");
+        assert_eq!(FloatDuration::hours(1.0).to_relative_string(), "in 1 hour");
+        assert_eq!(FloatDuration::days(1.0).to_relative_string(), "in 1 day");
+        assert_eq!(FloatDuration::years(1.0).to_relative_string(), "in 1 year");
+        assert_eq!(FloatDuration::seconds(-1.0).to_relative_string(), "1 second ago");
+        assert_eq!(FloatDuration::minutes(-1.0).to_relative_string(), "1 minute ago");
+        assert_eq!(FloatDuration::hours(-1.0).to_relative_string(), "1 hour ago");
+        assert_eq!(FloatDuration::days(-1.0).to_relative_string(), "1 day ago");
+        assert_eq!(FloatDuration::years(-1.0).to_relative_string(), "1 year ago");
+
+        assert_eq!(FloatDuration::zero().to_relative_string(), "just now");
+        assert_eq!(FloatDuration::milliseconds(200.0).to_relative_string(),
+                   "just now");
+        assert_eq!(FloatDuration::milliseconds(-999.0).to_relative_string(),
+                   "just now");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3.5h".parse::<FloatDuration>().unwrap(), FloatDuration::hours(3.5));
+        assert_eq!("120ms".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::milliseconds(120.0));
+        assert_eq!("2.5 days".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::days(2.5));
+        assert_eq!("90s".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::seconds(90.0));
+        assert_eq!(" 15 min ".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::minutes(15.0));
+        assert_eq!("10m".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::minutes(10.0));
+        assert_eq!("250us".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::microseconds(250.0));
+        assert_eq!("250\u{b5}s".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::microseconds(250.0));
+        assert_eq!("-40ns".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::nanoseconds(-40.0));
+        assert_eq!("2y".parse::<FloatDuration>().unwrap(), FloatDuration::years(2.0));
+        assert_eq!("3 weeks".parse::<FloatDuration>().unwrap(), FloatDuration::weeks(3.0));
+        assert_eq!("1.5e3ms".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::seconds(1.5));
+        assert_eq!("1.5e-30 seconds".parse::<FloatDuration>().unwrap(),
+                   FloatDuration::seconds(1.5e-30));
+        assert_eq!("1 hour".parse::<FloatDuration>().unwrap(), FloatDuration::hours(1.0));
+
+        assert!("".parse::<FloatDuration>().is_err());
+        assert!("10".parse::<FloatDuration>().is_err());
+        assert!("h".parse::<FloatDuration>().is_err());
+        assert!("10 fortnights".parse::<FloatDuration>().is_err());
+        assert!("1.2.3s".parse::<FloatDuration>().is_err());
+        assert!("5 s s".parse::<FloatDuration>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_humanized() {
+        assert_eq!(FloatDuration::parse_humanized("1h 30m 15s").unwrap(),
+                   FloatDuration::hours(1.0) + FloatDuration::minutes(30.0) +
+                   FloatDuration::seconds(15.0));
+        assert_eq!(FloatDuration::parse_humanized("2d4h").unwrap(),
+                   FloatDuration::days(2.0) + FloatDuration::hours(4.0));
+        assert_eq!(FloatDuration::parse_humanized(" 1.5 hours 250ms ").unwrap(),
+                   FloatDuration::seconds(5400.25));
+        assert_eq!(FloatDuration::parse_humanized("45s").unwrap(),
+                   FloatDuration::seconds(45.0));
+        assert_eq!(FloatDuration::parse_humanized("-1m 30s").unwrap(),
+                   FloatDuration::seconds(-90.0));
+        assert_eq!(FloatDuration::parse_humanized("30s 1m 30s").unwrap(),
+                   FloatDuration::minutes(2.0));
+
+        let duration = FloatDuration::days(3.0) + FloatDuration::minutes(7.0) +
+                       FloatDuration::milliseconds(125.0);
+        assert_eq!(FloatDuration::parse_humanized(&duration.humanize()).unwrap(), duration);
+        assert_eq!(FloatDuration::parse_humanized(&(-duration).humanize()).unwrap(),
+                   -duration);
+
+        assert!(FloatDuration::parse_humanized("").is_err());
+        assert!(FloatDuration::parse_humanized("   ").is_err());
+        assert!(FloatDuration::parse_humanized("-").is_err());
+        assert!(FloatDuration::parse_humanized("1h 30").is_err());
+        assert!(FloatDuration::parse_humanized("1h -30m").is_err());
+        assert!(FloatDuration::parse_humanized("1h 2 fortnights").is_err());
+        assert!(FloatDuration::parse_humanized("h").is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(FloatDuration::try_from("1.5 hours").unwrap(), FloatDuration::hours(1.5));
+        assert_eq!(FloatDuration::try_from("250ms").unwrap(),
+                   FloatDuration::milliseconds(250.0));
+        assert!(FloatDuration::try_from("1.5 fortnights").is_err());
+        assert!(FloatDuration::try_from("").is_err());
+
+        assert_eq!(FloatDuration::try_seconds(90.0), Ok(FloatDuration::seconds(90.0)));
+        assert_eq!(FloatDuration::try_seconds(-0.5), Ok(FloatDuration::seconds(-0.5)));
+        assert_eq!(FloatDuration::try_seconds(f64::NAN), Err(error::OutOfRangeError::new()));
+        assert!(FloatDuration::try_seconds(f64::INFINITY).is_err());
+        assert!(FloatDuration::try_seconds(f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_f64_conversion() {
+        assert_eq!(f64::from(FloatDuration::minutes(1.5)), 90.0);
+        assert_eq!(f64::from(FloatDuration::milliseconds(-250.0)), -0.25);
+        let secs: f64 = FloatDuration::hours(2.0).into();
+        assert_eq!(secs, 7200.0);
+        assert!(f64::from(FloatDuration::seconds(f64::NAN)).is_nan());
+
+        assert_eq!(FloatDuration::from(1.5), FloatDuration::seconds(1.5));
+        let duration: FloatDuration = (-0.5).into();
+        assert_eq!(duration, FloatDuration::milliseconds(-500.0));
+        assert!(FloatDuration::from(f64::NAN).is_nan());
+        for &secs in [0.0, -3.25, 1e9, 0.001].iter() {
+            assert_eq!(f64::from(FloatDuration::from(secs)), secs);
+        }
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        let durations = [FloatDuration::minutes(3.5),
+                         FloatDuration::days(325.25),
+                         FloatDuration::years(2.5),
+                         FloatDuration::seconds(12.7),
+                         FloatDuration::milliseconds(50.5),
+                         FloatDuration::nanoseconds(25.25),
+                         FloatDuration::seconds(1.5e-30),
+                         FloatDuration::hours(1.0) / 7.0,
+                         FloatDuration::minutes(-3.5),
+                         FloatDuration::zero()];
+
+        for &duration in durations.iter() {
+            let parsed = format!("{}", duration).parse::<FloatDuration>().unwrap();
+            assert!((parsed - duration).abs() <= duration.abs() * 1.0e-12,
+                    "{} parsed as {}",
+                    duration,
+                    parsed);
+        }
+    }
+
+    #[test]
+    fn test_finite_float_duration() {
+        use std::collections::BTreeSet;
+
+        assert!(FiniteFloatDuration::new(FloatDuration::seconds(f64::NAN)).is_none());
+        assert!(FiniteFloatDuration::new(FloatDuration::seconds(f64::INFINITY)).is_none());
+        assert_eq!(FiniteFloatDuration::new(FloatDuration::seconds(1.5)).unwrap().get(),
+                   FloatDuration::seconds(1.5));
+
+        let zero = FiniteFloatDuration::new(FloatDuration::zero()).unwrap();
+        let neg_zero = FiniteFloatDuration::new(-FloatDuration::zero()).unwrap();
+        assert_eq!(zero.cmp(&neg_zero), cmp::Ordering::Equal);
+
+        let set: BTreeSet<_> = [FloatDuration::minutes(1.0),
+                                FloatDuration::seconds(-2.0),
+                                FloatDuration::hours(1.0),
+                                FloatDuration::seconds(60.0),
+                                FloatDuration::zero(),
+                                -FloatDuration::zero()]
+            .iter()
+            .filter_map(|&d| FiniteFloatDuration::new(d))
+            .collect();
+        let sorted: Vec<FloatDuration> = set.into_iter().map(FloatDuration::from).collect();
+        assert_eq!(sorted,
+                   vec![FloatDuration::seconds(-2.0),
+                        FloatDuration::zero(),
+                        FloatDuration::minutes(1.0),
+                        FloatDuration::hours(1.0)]);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashMap;
+
+        let mut cache = HashMap::new();
+        cache.insert(FloatDuration::seconds(1.5), "a");
+        cache.insert(FloatDuration::minutes(2.0), "b");
+
+        assert_eq!(cache.get(&FloatDuration::milliseconds(1500.0)), Some(&"a"));
+        assert_eq!(cache.get(&FloatDuration::seconds(120.0)), Some(&"b"));
+        assert_eq!(cache.get(&FloatDuration::seconds(2.0)), None);
+
+        let key = |d| FiniteFloatDuration::new(d).unwrap();
+        let mut finite_cache = HashMap::new();
+        finite_cache.insert(key(-FloatDuration::zero()), "c");
+        assert_eq!(finite_cache.get(&key(FloatDuration::zero())), Some(&"c"));
+    }
+
     #[test]
     fn test_sum() {
         let zero: [FloatDuration; 0] = [];
@@ -854,6 +3552,82 @@ mod tests {
                    FloatDuration::days(2.0));
     }
 
+    #[test]
+    fn test_sum_result() {
+        let all_ok: [Result<FloatDuration, &str>; 2] = [Ok(FloatDuration::seconds(1.5)),
+                                                        Ok(FloatDuration::seconds(2.0))];
+        assert_eq!(all_ok.iter().cloned().sum::<Result<FloatDuration, &str>>(),
+                   Ok(FloatDuration::seconds(3.5)));
+
+        let with_err = [Ok(FloatDuration::seconds(1.0)),
+                        Err("first"),
+                        Ok(FloatDuration::seconds(2.0)),
+                        Err("second")];
+        assert_eq!(with_err.iter().cloned().sum::<Result<FloatDuration, &str>>(),
+                   Err("first"));
+
+        let std_durations = [time::Duration::new(1, 0), time::Duration::new(0, 500_000_000)];
+        let total: Result<FloatDuration, ()> =
+            std_durations.iter().map(|&d| FloatDuration::from_duration(d)).sum();
+        assert_eq!(total, Ok(FloatDuration::seconds(1.5)));
+    }
+
+    #[test]
+    fn test_harmonic_mean() {
+        let empty: Vec<FloatDuration> = vec![];
+        assert_eq!(FloatDuration::harmonic_mean(empty), None);
+        assert_eq!(FloatDuration::harmonic_mean(vec![FloatDuration::zero()]), None);
+
+        assert_eq!(FloatDuration::harmonic_mean(vec![FloatDuration::minutes(2.0)]),
+                   Some(FloatDuration::minutes(2.0)));
+        assert_eq!(FloatDuration::harmonic_mean(vec![FloatDuration::seconds(2.0),
+                                                     FloatDuration::seconds(4.0),
+                                                     FloatDuration::seconds(4.0)]),
+                   Some(FloatDuration::seconds(3.0)));
+        assert_eq!(FloatDuration::harmonic_mean(vec![FloatDuration::seconds(1.0),
+                                                     FloatDuration::zero(),
+                                                     FloatDuration::seconds(3.0)]),
+                   Some(FloatDuration::seconds(1.5)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_next_aligned() {
+        let period = FloatDuration::seconds(3.0);
+        let phase = FloatDuration::seconds(1.0);
+
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::seconds(11.0), period, phase),
+                   FloatDuration::seconds(13.0));
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::seconds(10.0), period, phase),
+                   FloatDuration::seconds(13.0));
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::zero(), period, phase),
+                   FloatDuration::seconds(1.0));
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::seconds(-5.0), period, phase),
+                   FloatDuration::seconds(-2.0));
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::seconds(-3.5), period, phase),
+                   FloatDuration::seconds(-2.0));
+
+        assert_eq!(FloatDuration::next_aligned(FloatDuration::zero(),
+                                               FloatDuration::minutes(1.0),
+                                               FloatDuration::zero()),
+                   FloatDuration::minutes(1.0));
+
+        let after = FloatDuration::seconds(8.5);
+        let next = FloatDuration::next_aligned(after,
+                                               FloatDuration::seconds(1.7000000000000002),
+                                               FloatDuration::zero());
+        assert!(next > after);
+    }
+
+    #[cfg(feature = "std")]
+    #[should_panic]
+    #[test]
+    fn test_next_aligned_panic() {
+        FloatDuration::next_aligned(FloatDuration::zero(),
+                                    FloatDuration::zero(),
+                                    FloatDuration::zero());
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_chrono_conversion() {
@@ -882,13 +3656,93 @@ mod tests {
 
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_to_chrono_negative() {
+        let durations = [FloatDuration::seconds(-1.5),
+                         FloatDuration::milliseconds(-250.050),
+                         FloatDuration::hours(-26.0),
+                         FloatDuration::seconds(-0.1),
+                         FloatDuration::seconds(12.75)];
+        for &duration in durations.iter() {
+            // The result is the exact negation of converting the magnitude.
+            let magnitude = duration.abs().to_chrono().unwrap();
+            let expected = if duration.is_negative() { -magnitude } else { magnitude };
+            assert_eq!(duration.to_chrono().unwrap(), expected);
+        }
+
+        assert_eq!(FloatDuration::seconds(-1.9999999999).to_chrono().unwrap(),
+                   chrono::Duration::seconds(-2));
+        assert_eq!(FloatDuration::seconds(-0.1).to_chrono().unwrap(),
+                   chrono::Duration::milliseconds(-100));
+        assert_eq!(FloatDuration::seconds(-1.5).to_chrono().unwrap(),
+                   chrono::Duration::seconds(-1) + chrono::Duration::milliseconds(-500));
+        assert!(FloatDuration::min_value().to_chrono().is_err());
+        assert!(FloatDuration::seconds(f64::NAN).to_chrono().is_err());
+        assert!(FloatDuration::seconds(f64::NEG_INFINITY).to_chrono().is_err());
+        assert!(FloatDuration::years(1e12).to_chrono().is_err());
+        assert!(FloatDuration::years(-1e9).to_chrono().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversion_error() {
+        use error::ConversionError;
+
+        assert_eq!(FloatDuration::years(1e12).to_chrono(), Err(ConversionError::Overflow));
+        assert_eq!(FloatDuration::years(-1e12).to_chrono(), Err(ConversionError::Overflow));
+        assert_eq!(FloatDuration::min_value().to_chrono(), Err(ConversionError::Overflow));
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_chrono(),
+                   Err(ConversionError::NotFinite));
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).to_chrono(),
+                   Err(ConversionError::NotFinite));
+        assert!(FloatDuration::seconds(-1.0).to_chrono().is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversion_large() {
+        // Three centuries overflows an i64 number of nanoseconds.
+        let centuries = chrono::Duration::days(3 * 36500);
+        assert!(centuries.num_nanoseconds().is_none());
+
+        let duration = FloatDuration::from_chrono(centuries + chrono::Duration::microseconds(250));
+        let extra = duration - FloatDuration::centuries(3.0);
+        assert!((extra.as_microseconds() - 250.0).abs() < 5.0);
+
+        let negative = FloatDuration::from_chrono(-centuries - chrono::Duration::microseconds(250));
+        let extra = negative + FloatDuration::centuries(3.0);
+        assert!((extra.as_microseconds() + 250.0).abs() < 5.0);
+        assert_eq!(FloatDuration::from_chrono(-centuries), -FloatDuration::centuries(3.0));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_std_chrono_from_duration() {
+        assert_eq!(chrono::Duration::from_duration(time::Duration::new(90, 500_000_000)),
+                   Ok(chrono::Duration::milliseconds(90_500)));
+        assert_eq!(chrono::Duration::from_duration(time::Duration::from_millis(250)),
+                   Ok(chrono::Duration::milliseconds(250)));
+        assert!(chrono::Duration::from_duration(time::Duration::new(u64::MAX, 0)).is_err());
+
+        assert_eq!(time::Duration::from_duration(chrono::Duration::minutes(3)),
+                   Ok(time::Duration::from_secs(180)));
+        assert_eq!(time::Duration::from_duration(chrono::Duration::microseconds(1_500)),
+                   Ok(time::Duration::from_micros(1_500)));
+        assert!(time::Duration::from_duration(chrono::Duration::seconds(-1)).is_err());
+
+        let chrono_duration: Result<chrono::Duration, _> =
+            time::Duration::from_secs(3600).into_duration();
+        assert_eq!(chrono_duration, Ok(chrono::Duration::hours(1)));
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_chrono_timepoint() {
         use chrono::{TimeZone, Utc, Local};
 
-        let date1 = Utc.ymd(2017, 5, 25).and_hms(10, 0, 0);
-        let date2 = Utc.ymd(2017, 5, 26).and_hms(12, 0, 0);
+        let date1 = Utc.with_ymd_and_hms(2017, 5, 25, 10, 0, 0).unwrap();
+        let date2 = Utc.with_ymd_and_hms(2017, 5, 26, 12, 0, 0).unwrap();
 
         assert_eq!(date2.float_duration_since(date1).unwrap(),
                    FloatDuration::days(1.0) + FloatDuration::hours(2.0));
@@ -902,30 +3756,135 @@ mod tests {
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
-        use serde_test::{Token, assert_tokens, assert_de_tokens};
+        use serde_test::{Token, Configure, assert_tokens, assert_de_tokens};
 
         let duration = FloatDuration::seconds(1.5);
-        assert_tokens(&duration, &[Token::F64(duration.as_seconds())]);
+        assert_tokens(&duration.compact(), &[Token::F64(duration.as_seconds())]);
 
-        assert_tokens(&FloatDuration::zero(), &[Token::F64(0.0)]);
+        assert_tokens(&FloatDuration::zero().compact(), &[Token::F64(0.0)]);
 
         let duration2 = FloatDuration::hours(3.0);
-        assert_tokens(&duration2, &[Token::F64(3.0 * SECS_PER_HOUR)]);
+        assert_tokens(&duration2.compact(), &[Token::F64(3.0 * SECS_PER_HOUR)]);
 
         let duration3 = FloatDuration::days(5.0) + FloatDuration::minutes(35.2) +
                         FloatDuration::milliseconds(100.0);
-        assert_tokens(&duration3, &[Token::F64(duration3.as_seconds())]);
+        assert_tokens(&duration3.compact(),
+                      &[Token::F64(duration3.as_seconds())]);
 
-        assert_tokens(&FloatDuration::seconds(-10.0), &[Token::F64(-10.0)]);
+        assert_tokens(&FloatDuration::seconds(-10.0).compact(), &[Token::F64(-10.0)]);
 
-        assert_de_tokens(&FloatDuration::seconds(30.0), &[Token::F32(30.0)]);
+        assert_de_tokens(&FloatDuration::seconds(30.0).readable(), &[Token::F32(30.0)]);
+        assert_de_tokens(&FloatDuration::seconds(30.0).compact(), &[Token::F32(30.0)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_readable() {
+        use serde_test::{Token, Configure, assert_tokens, assert_de_tokens};
+
+        assert_tokens(&FloatDuration::hours(1.5).readable(),
+                      &[Token::Str("1.5 hours")]);
+        assert_tokens(&FloatDuration::zero().readable(), &[Token::Str("0 seconds")]);
+        assert_tokens(&FloatDuration::minutes(-3.5).readable(),
+                      &[Token::Str("-3.5 minutes")]);
+        assert_tokens(&FloatDuration::milliseconds(250.0).readable(),
+                      &[Token::Str("250 milliseconds")]);
+        assert_tokens(&FloatDuration::seconds(60.593826614899996).readable(),
+                      &[Token::Str("60.593826614899996 seconds")]);
+
+        assert_de_tokens(&FloatDuration::milliseconds(120.0).readable(),
+                         &[Token::Str("120ms")]);
+        assert_de_tokens(&FloatDuration::hours(1.5).readable(), &[Token::F64(5400.0)]);
+        assert_de_tokens(&FloatDuration::hours(1.5).compact(),
+                         &[Token::Str("1.5h")]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json() {
+        use serde_json;
+
+        let durations = [FloatDuration::hours(1.5),
+                         FloatDuration::milliseconds(12.5),
+                         FloatDuration::days(-3.0),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            let json = serde_json::to_string(&duration).unwrap();
+            assert_eq!(serde_json::from_str::<FloatDuration>(&json).unwrap(), duration);
+        }
+
+        assert_eq!(serde_json::to_string(&FloatDuration::hours(1.5)).unwrap(),
+                   "\"1.5 hours\"");
+        assert_eq!(serde_json::to_string(&FloatDuration::seconds(f64::INFINITY)).unwrap(),
+                   "\"inf seconds\"");
+        let nan_json = serde_json::to_string(&FloatDuration::seconds(f64::NAN)).unwrap();
+        assert!(serde_json::from_str::<FloatDuration>(&nan_json).unwrap().is_nan());
+        assert_eq!(serde_json::from_str::<FloatDuration>("\"90s\"").unwrap(),
+                   FloatDuration::seconds(90.0));
+        assert_eq!(serde_json::from_str::<FloatDuration>("90.5").unwrap(),
+                   FloatDuration::seconds(90.5));
+        assert_eq!(serde_json::from_str::<FloatDuration>("90").unwrap(),
+                   FloatDuration::seconds(90.0));
+        assert_eq!(serde_json::from_str::<FloatDuration>("-5").unwrap(),
+                   FloatDuration::seconds(-5.0));
+        assert_eq!(serde_json::from_str::<FloatDuration>("18446744073709551615").unwrap(),
+                   FloatDuration::seconds(u64::MAX as f64));
+        assert!(serde_json::from_str::<FloatDuration>("\"90\"").is_err());
+        assert!(serde_json::from_str::<FloatDuration>("true").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        use serde_json;
+        use serde_test_util::random_durations;
+
+        for duration in random_durations(40_000) {
+            let json = serde_json::to_string(&duration).unwrap();
+            let back = serde_json::from_str::<FloatDuration>(&json).unwrap();
+            if duration.is_nan() {
+                assert!(back.is_nan());
+            } else {
+                assert_eq!(back.as_seconds().to_bits(),
+                           duration.as_seconds().to_bits(),
+                           "{} did not round-trip",
+                           json);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_integer() {
+        use serde_test::{Token, Configure, assert_de_tokens};
+
+        assert_de_tokens(&FloatDuration::seconds(10.0).compact(), &[Token::I32(10)]);
+        assert_de_tokens(&FloatDuration::seconds(-10.0).compact(), &[Token::I32(-10)]);
+        assert_de_tokens(&FloatDuration::seconds(10.0).compact(), &[Token::U32(10)]);
+        assert_de_tokens(&FloatDuration::hours(1.0).compact(), &[Token::I64(3600)]);
+        assert_de_tokens(&FloatDuration::hours(1.0).compact(), &[Token::U64(3600)]);
+        assert_de_tokens(&FloatDuration::zero().compact(), &[Token::U8(0)]);
+        assert_de_tokens(&FloatDuration::hours(1.0).readable(), &[Token::I64(3600)]);
+        assert_de_tokens(&FloatDuration::hours(1.0).readable(), &[Token::U64(3600)]);
+
+        assert_de_tokens(&FloatDuration::seconds(5.0).compact(), &[Token::I64(5)]);
+        assert_de_tokens(&FloatDuration::seconds(5.0).compact(), &[Token::U64(5)]);
+        assert_de_tokens(&FloatDuration::seconds(-5.0).readable(), &[Token::I64(-5)]);
     }
 
     #[cfg(feature = "serde")]
-    #[should_panic]
     #[test]
-    fn test_serde_integer_panic() {
-        use serde_test::{Token, assert_de_tokens};
-        assert_de_tokens(&FloatDuration::seconds(10.0), &[Token::I32(10)]);
+    fn test_serde_invalid() {
+        use serde_test::{Token, Readable, assert_de_tokens_error};
+
+        let expected = "expected a numeric value of seconds or a duration string";
+        assert_de_tokens_error::<Readable<FloatDuration>>(&[Token::Str("10 fortnights")],
+                                                          &format!("invalid value: string \
+                                                                    \"10 fortnights\", {}",
+                                                                   expected));
+        assert_de_tokens_error::<Readable<FloatDuration>>(&[Token::Bool(true)],
+                                                          &format!("invalid type: boolean \
+                                                                    `true`, {}",
+                                                                   expected));
     }
 }
@@ -1,11 +1,12 @@
 //! Error handling facilities.
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "chrono")]
 use chrono;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct OutOfRangeError {}
 
 impl OutOfRangeError {
@@ -14,6 +15,7 @@ impl OutOfRangeError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for OutOfRangeError {
     fn description(&self) -> &str {
         "The converted duration value is out of range."
@@ -22,7 +24,7 @@ impl Error for OutOfRangeError {
 
 impl fmt::Display for OutOfRangeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        f.write_str("The converted duration value is out of range.")
     }
 }
 
@@ -32,3 +34,71 @@ impl From<chrono::OutOfRangeError> for OutOfRangeError {
         OutOfRangeError {}
     }
 }
+
+/// An error produced when a `FloatDuration` cannot be converted to another
+/// duration type, describing why the conversion failed.
+///
+/// A `ConversionError` may be converted into an `OutOfRangeError` with `?` or
+/// `into()`, discarding the reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The duration is negative, but the target type cannot represent negative
+    /// durations.
+    Negative,
+    /// The duration is too large in magnitude for the target type.
+    Overflow,
+    /// The duration is infinite or NaN.
+    NotFinite,
+}
+
+impl ConversionError {
+    fn message(&self) -> &'static str {
+        match *self {
+            ConversionError::Negative => "The converted duration value is negative.",
+            ConversionError::Overflow => "The converted duration value is too large.",
+            ConversionError::NotFinite => "The converted duration value is not finite.",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ConversionError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl From<ConversionError> for OutOfRangeError {
+    fn from(_: ConversionError) -> OutOfRangeError {
+        OutOfRangeError {}
+    }
+}
+
+/// An error produced when a string cannot be parsed as a duration.
+#[derive(Debug, Clone, Default)]
+pub struct ParseDurationError {}
+
+impl ParseDurationError {
+    pub fn new() -> ParseDurationError {
+        ParseDurationError {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseDurationError {
+    fn description(&self) -> &str {
+        "The string is not a valid duration."
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("The string is not a valid duration.")
+    }
+}
@@ -0,0 +1,703 @@
+//! Alternative textual representations of durations.
+//!
+//! The `Display` impl of `FloatDuration` always prints a single value in the
+//! largest unit that fits. The functions in this module provide other
+//! renderings that are more suitable in some contexts.
+use duration::{FloatDuration, MILLIS_PER_SEC, MICROS_PER_SEC, NANOS_PER_SEC, SECS_PER_YEAR,
+               SECS_PER_WEEK, SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE};
+#[cfg(feature = "std")]
+use error::{ConversionError, ParseDurationError};
+
+/// The units used by `humanize`, from largest to smallest, in milliseconds.
+#[cfg(feature = "std")]
+const HUMANIZE_UNITS: [(u64, &str); 5] = [(86_400_000, "d"),
+                                          (3_600_000, "h"),
+                                          (60_000, "m"),
+                                          (1_000, "s"),
+                                          (1, "ms")];
+
+#[cfg(feature = "std")]
+impl FloatDuration {
+    /// Format this duration as a compound string of units, such as `"1h 23m 45s"`.
+    ///
+    /// The duration is rounded to the nearest millisecond and split into days,
+    /// hours, minutes, seconds and milliseconds. Zero components are omitted,
+    /// and negative durations are prefixed with a single `-`. A zero duration is
+    /// rendered as `"0s"`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let duration = FloatDuration::hours(1.0) + FloatDuration::minutes(23.0) +
+    ///                FloatDuration::seconds(45.0);
+    /// assert_eq!(duration.humanize(), "1h 23m 45s");
+    /// ```
+    pub fn humanize(&self) -> String {
+        self.humanize_components(HUMANIZE_UNITS.len())
+    }
+
+    /// Format this duration as a compound string using at most `max_components`
+    /// units.
+    ///
+    /// The units shown are the largest non-zero unit and the units that directly
+    /// follow it, up to `max_components` in total. Smaller units are truncated,
+    /// and zero components within the shown range are omitted. Otherwise, this
+    /// behaves like `humanize`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let duration = FloatDuration::days(2.0) + FloatDuration::hours(3.0) +
+    ///                FloatDuration::minutes(59.0);
+    /// assert_eq!(duration.humanize_components(2), "2d 3h");
+    /// ```
+    pub fn humanize_components(&self, max_components: usize) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+
+        let mut remaining = (self.as_seconds().abs() * MILLIS_PER_SEC).round() as u64;
+        let mut parts = Vec::new();
+        let mut shown_units = 0;
+
+        for &(unit_millis, suffix) in HUMANIZE_UNITS.iter() {
+            let count = remaining / unit_millis;
+            remaining %= unit_millis;
+
+            if count == 0 && shown_units == 0 {
+                continue;
+            }
+            if shown_units == max_components {
+                break;
+            }
+            shown_units += 1;
+            if count > 0 {
+                parts.push(format!("{}{}", count, suffix));
+            }
+        }
+
+        if parts.is_empty() {
+            String::from("0s")
+        } else if self.is_negative() {
+            format!("-{}", parts.join(" "))
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Format this duration as a fixed-width clock string, such as `"001:02:03:04"`.
+    ///
+    /// The fields are days, hours, minutes and seconds, as computed by `decompose`.
+    /// The days are zero-padded to at least `day_width` digits, and the other
+    /// fields to two digits, so that durations of similar magnitude line up in
+    /// logs. Fractional seconds are truncated. Negative durations of at least one
+    /// second are prefixed with `-`.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// let duration = FloatDuration::days(1.0) + FloatDuration::hours(2.0) +
+    ///                FloatDuration::minutes(3.0) + FloatDuration::seconds(4.5);
+    /// assert_eq!(duration.format_clock(3), "001:02:03:04");
+    /// ```
+    pub fn format_clock(&self, day_width: usize) -> String {
+        let time = self.decompose();
+        let is_zero = time.days == 0 && time.hours == 0 && time.minutes == 0 &&
+                      time.seconds == 0;
+        let sign = if time.sign < 0 && !is_zero { "-" } else { "" };
+        format!("{}{:0width$}:{:02}:{:02}:{:02}",
+                sign,
+                time.days,
+                time.hours,
+                time.minutes,
+                time.seconds,
+                width = day_width)
+    }
+
+    /// Format this duration as an ISO 8601 duration string, such as `"PT1H30M"`.
+    ///
+    /// The duration is expressed in days, hours, minutes and seconds, with
+    /// seconds written to at most nanosecond precision. Zero components are
+    /// omitted, and a zero duration is written as `"PT0S"`. Years and months are
+    /// never emitted since their lengths are ambiguous. Negative durations are
+    /// prefixed with `-`, following the common extension to the standard.
+    ///
+    /// # Errors
+    /// Returns `ConversionError::NotFinite` if the duration is infinite or NaN, and
+    /// `ConversionError::Overflow` if it has more than `u64::MAX` whole seconds.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::minutes(90.0).to_iso8601().unwrap(), "PT1H30M");
+    /// assert_eq!(FloatDuration::hours(26.0).to_iso8601().unwrap(), "P1DT2H");
+    /// ```
+    pub fn to_iso8601(&self) -> Result<String, ConversionError> {
+        if !self.is_finite() {
+            return Err(ConversionError::NotFinite);
+        }
+        let abs_secs = self.as_seconds().abs();
+        if abs_secs >= u64::MAX as f64 {
+            return Err(ConversionError::Overflow);
+        }
+        let mut whole_secs = abs_secs.trunc() as u64;
+        let mut nanos = (abs_secs.fract() * NANOS_PER_SEC).round() as u64;
+        if nanos >= NANOS_PER_SEC as u64 {
+            whole_secs += 1;
+            nanos = 0;
+        }
+
+        let time = FloatDuration::seconds(whole_secs as f64).decompose();
+        let (days, hours, minutes, seconds) = (time.days, time.hours, time.minutes, time.seconds);
+
+        let mut result = String::new();
+        if self.is_negative() && (whole_secs > 0 || nanos > 0) {
+            result.push('-');
+        }
+        result.push('P');
+        if days > 0 {
+            result.push_str(&format!("{}D", days));
+        }
+        if hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 || days == 0 {
+            result.push('T');
+            if hours > 0 {
+                result.push_str(&format!("{}H", hours));
+            }
+            if minutes > 0 {
+                result.push_str(&format!("{}M", minutes));
+            }
+            if nanos > 0 {
+                let fraction = format!("{:09}", nanos);
+                result.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+            } else if seconds > 0 || (days == 0 && hours == 0 && minutes == 0) {
+                result.push_str(&format!("{}S", seconds));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Parse an ISO 8601 duration string, such as `"PT1H30M"` or `"P1DT2.5S"`.
+    ///
+    /// The date part may contain years (`Y`), weeks (`W`) and days (`D`), and the
+    /// time part hours (`H`), minutes (`M`) and seconds (`S`). Every component may
+    /// be fractional, using either `.` or `,` as the decimal separator. As elsewhere
+    /// in this crate, a year is considered to be exactly 365 days. Months are not
+    /// supported since their length is ambiguous. A leading `-` negates the
+    /// duration.
+    ///
+    /// ```rust
+    /// use float_duration::FloatDuration;
+    ///
+    /// assert_eq!(FloatDuration::from_iso8601("PT1.5S").unwrap(),
+    ///            FloatDuration::seconds(1.5));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a `ParseDurationError` if the string is not a valid ISO 8601
+    /// duration, if it contains a month component, or if components are out of
+    /// order.
+    pub fn from_iso8601(s: &str) -> Result<FloatDuration, ParseDurationError> {
+        let (negative, rest) = if let Some(rest) = s.strip_prefix('-') {
+            (true, rest)
+        } else {
+            (false, s.strip_prefix('+').unwrap_or(s))
+        };
+        let rest = rest.strip_prefix('P').ok_or_else(ParseDurationError::new)?;
+        let (date, time) = match rest.find('T') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+
+        let date_units = [('Y', SECS_PER_YEAR), ('W', SECS_PER_WEEK), ('D', SECS_PER_DAY)];
+        let time_units = [('H', SECS_PER_HOUR), ('M', SECS_PER_MINUTE), ('S', 1.0)];
+
+        let (mut secs, mut count) = parse_iso8601_components(date, &date_units)?;
+        if let Some(time) = time {
+            let (time_secs, time_count) = parse_iso8601_components(time, &time_units)?;
+            if time_count == 0 {
+                return Err(ParseDurationError::new());
+            }
+            secs += time_secs;
+            count += time_count;
+        }
+
+        if count == 0 {
+            Err(ParseDurationError::new())
+        } else if negative {
+            Ok(FloatDuration::seconds(-secs))
+        } else {
+            Ok(FloatDuration::seconds(secs))
+        }
+    }
+}
+
+/// Parse a sequence of ISO 8601 `<number><designator>` components.
+///
+/// Designators must appear in the order given by `units`. Returns the total
+/// number of seconds and the number of components parsed.
+#[cfg(feature = "std")]
+fn parse_iso8601_components(s: &str,
+                            units: &[(char, f64)])
+                            -> Result<(f64, usize), ParseDurationError> {
+    let mut rest = s;
+    let mut total = 0.0;
+    let mut count = 0;
+    let mut next_unit = 0;
+
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .ok_or_else(ParseDurationError::new)?;
+        let value = rest[..end].replace(',', ".")
+            .parse::<f64>()
+            .map_err(|_| ParseDurationError::new())?;
+
+        let designator = rest[end..].chars().next().ok_or_else(ParseDurationError::new)?;
+        let index = units[next_unit..].iter()
+            .position(|&(unit, _)| unit == designator)
+            .ok_or_else(ParseDurationError::new)? + next_unit;
+
+        total += value * units[index].1;
+        count += 1;
+        next_unit = index + 1;
+        rest = &rest[end + designator.len_utf8()..];
+    }
+    Ok((total, count))
+}
+
+/// A unit of time that a duration may be expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// Years of exactly 365 days.
+    Years,
+    /// Weeks of 7 days.
+    Weeks,
+    /// Days of 24 hours.
+    Days,
+    /// Hours.
+    Hours,
+    /// Minutes.
+    Minutes,
+    /// Seconds.
+    Seconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Nanoseconds.
+    Nanoseconds,
+}
+
+/// The units chosen from by `Display`, from largest to smallest.
+const DISPLAY_UNITS: [TimeUnit; 8] = [TimeUnit::Years,
+                                      TimeUnit::Days,
+                                      TimeUnit::Hours,
+                                      TimeUnit::Minutes,
+                                      TimeUnit::Seconds,
+                                      TimeUnit::Milliseconds,
+                                      TimeUnit::Microseconds,
+                                      TimeUnit::Nanoseconds];
+
+impl TimeUnit {
+    /// Every unit, from largest to smallest.
+    pub const ALL: [TimeUnit; 9] = [TimeUnit::Years,
+                                    TimeUnit::Weeks,
+                                    TimeUnit::Days,
+                                    TimeUnit::Hours,
+                                    TimeUnit::Minutes,
+                                    TimeUnit::Seconds,
+                                    TimeUnit::Milliseconds,
+                                    TimeUnit::Microseconds,
+                                    TimeUnit::Nanoseconds];
+
+    /// Return the unit whose plural name, as returned by `name`, is `name`.
+    pub fn from_name(name: &str) -> Option<TimeUnit> {
+        TimeUnit::ALL.iter().cloned().find(|unit| unit.name() == name)
+    }
+
+    /// Return the number of seconds in one of this unit.
+    pub fn as_seconds(&self) -> f64 {
+        match *self {
+            TimeUnit::Years => SECS_PER_YEAR,
+            TimeUnit::Weeks => SECS_PER_WEEK,
+            TimeUnit::Days => SECS_PER_DAY,
+            TimeUnit::Hours => SECS_PER_HOUR,
+            TimeUnit::Minutes => SECS_PER_MINUTE,
+            TimeUnit::Seconds => 1.0,
+            TimeUnit::Milliseconds => 1.0 / MILLIS_PER_SEC,
+            TimeUnit::Microseconds => 1.0 / MICROS_PER_SEC,
+            TimeUnit::Nanoseconds => 1.0 / NANOS_PER_SEC,
+        }
+    }
+    /// Return the plural name of this unit, such as `"seconds"`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TimeUnit::Years => "years",
+            TimeUnit::Weeks => "weeks",
+            TimeUnit::Days => "days",
+            TimeUnit::Hours => "hours",
+            TimeUnit::Minutes => "minutes",
+            TimeUnit::Seconds => "seconds",
+            TimeUnit::Milliseconds => "milliseconds",
+            TimeUnit::Microseconds => "microseconds",
+            TimeUnit::Nanoseconds => "nanoseconds",
+        }
+    }
+    /// Return the abbreviated suffix of this unit, such as `"s"`.
+    pub fn symbol(&self) -> &'static str {
+        match *self {
+            TimeUnit::Years => "y",
+            TimeUnit::Weeks => "w",
+            TimeUnit::Days => "d",
+            TimeUnit::Hours => "h",
+            TimeUnit::Minutes => "m",
+            TimeUnit::Seconds => "s",
+            TimeUnit::Milliseconds => "ms",
+            TimeUnit::Microseconds => "\u{b5}s",
+            TimeUnit::Nanoseconds => "ns",
+        }
+    }
+    /// Return the fractional number of this unit represented by `duration`.
+    pub fn convert(&self, duration: FloatDuration) -> f64 {
+        match *self {
+            TimeUnit::Years => duration.as_years(),
+            TimeUnit::Weeks => duration.as_weeks(),
+            TimeUnit::Days => duration.as_days(),
+            TimeUnit::Hours => duration.as_hours(),
+            TimeUnit::Minutes => duration.as_minutes(),
+            TimeUnit::Seconds => duration.as_seconds(),
+            TimeUnit::Milliseconds => duration.as_milliseconds(),
+            TimeUnit::Microseconds => duration.as_microseconds(),
+            TimeUnit::Nanoseconds => duration.as_nanoseconds(),
+        }
+    }
+    /// Return a `FloatDuration` spanning `value` of this unit, the inverse of `convert`.
+    pub fn duration(&self, value: f64) -> FloatDuration {
+        match *self {
+            TimeUnit::Years => FloatDuration::years(value),
+            TimeUnit::Weeks => FloatDuration::weeks(value),
+            TimeUnit::Days => FloatDuration::days(value),
+            TimeUnit::Hours => FloatDuration::hours(value),
+            TimeUnit::Minutes => FloatDuration::minutes(value),
+            TimeUnit::Seconds => FloatDuration::seconds(value),
+            TimeUnit::Milliseconds => FloatDuration::milliseconds(value),
+            TimeUnit::Microseconds => FloatDuration::microseconds(value),
+            TimeUnit::Nanoseconds => FloatDuration::nanoseconds(value),
+        }
+    }
+
+    /// Return the unit `Display` uses for `duration`: the largest unit of which
+    /// the duration spans at least one, or seconds for zero.
+    ///
+    /// Returns `None` for durations smaller than a nanosecond and for NaN.
+    pub fn display_unit(duration: FloatDuration) -> Option<TimeUnit> {
+        let magnitude = duration.as_seconds().abs();
+        if magnitude == 0.0 {
+            return Some(TimeUnit::Seconds);
+        }
+        DISPLAY_UNITS.iter().cloned().find(|unit| magnitude >= unit.as_seconds())
+    }
+}
+
+/// A configurable formatter for `FloatDuration` values.
+///
+/// By default, `FloatDurationFormatter` produces the same output as the
+/// `Display` implementation of `FloatDuration`. Each option may then be changed
+/// with the builder methods.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::format::{FloatDurationFormatter, TimeUnit};
+///
+/// let formatter = FloatDurationFormatter::new()
+///     .unit(TimeUnit::Minutes)
+///     .precision(1)
+///     .short_units(true);
+/// assert_eq!(formatter.format(FloatDuration::hours(2.0)), "120.0m");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FloatDurationFormatter {
+    unit: Option<TimeUnit>,
+    precision: Option<usize>,
+    short_units: bool,
+    explicit_sign: bool,
+}
+
+#[cfg(feature = "std")]
+impl FloatDurationFormatter {
+    /// Create a new formatter that behaves like `Display`.
+    pub fn new() -> FloatDurationFormatter {
+        FloatDurationFormatter::default()
+    }
+    /// Always express durations in `unit`, rather than choosing one by magnitude.
+    pub fn unit(mut self, unit: TimeUnit) -> FloatDurationFormatter {
+        self.unit = Some(unit);
+        self
+    }
+    /// Print exactly `precision` digits after the decimal point.
+    pub fn precision(mut self, precision: usize) -> FloatDurationFormatter {
+        self.precision = Some(precision);
+        self
+    }
+    /// Use abbreviated unit suffixes such as `"1.5s"` instead of `"1.5 seconds"`.
+    pub fn short_units(mut self, short_units: bool) -> FloatDurationFormatter {
+        self.short_units = short_units;
+        self
+    }
+    /// Prefix non-negative durations with an explicit `+`.
+    pub fn explicit_sign(mut self, explicit_sign: bool) -> FloatDurationFormatter {
+        self.explicit_sign = explicit_sign;
+        self
+    }
+
+    /// Format `duration` according to the options of this formatter.
+    ///
+    /// When no unit is set, durations smaller than a nanosecond are written in
+    /// seconds using scientific notation, as `Display` does.
+    pub fn format(&self, duration: FloatDuration) -> String {
+        let (value, unit, exponent) = match self.unit.or_else(|| TimeUnit::display_unit(duration)) {
+            Some(unit) => (unit.convert(duration), unit, false),
+            None => (duration.as_seconds(), TimeUnit::Seconds, true),
+        };
+        // Negative zero is printed without its sign, as with `Display`.
+        let value = if duration.is_zero() { 0.0 } else { value };
+
+        let mut number = match (self.precision, exponent) {
+            (Some(precision), false) => format!("{:.*}", precision, value),
+            (Some(precision), true) => format!("{:.*e}", precision, value),
+            (None, false) => format!("{}", value),
+            (None, true) => format!("{:e}", value),
+        };
+        if self.explicit_sign && !value.is_nan() && !number.starts_with('-') {
+            number.insert(0, '+');
+        }
+
+        if self.short_units {
+            format!("{}{}", number, unit.symbol())
+        } else {
+            format!("{} {}", number, unit.name())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_humanize() {
+        let duration1 = FloatDuration::hours(1.0) + FloatDuration::minutes(23.0) +
+                        FloatDuration::seconds(45.0);
+        assert_eq!(duration1.humanize(), "1h 23m 45s");
+        assert_eq!((FloatDuration::days(2.0) + FloatDuration::hours(3.0)).humanize(),
+                   "2d 3h");
+        assert_eq!((FloatDuration::days(1.0) + FloatDuration::seconds(5.0)).humanize(),
+                   "1d 5s");
+        assert_eq!(FloatDuration::seconds(1.5).humanize(), "1s 500ms");
+        assert_eq!(FloatDuration::milliseconds(250.0).humanize(), "250ms");
+        assert_eq!(FloatDuration::seconds(61.1).humanize(), "1m 1s 100ms");
+        assert_eq!(FloatDuration::days(400.0).humanize(), "400d");
+
+        assert_eq!(FloatDuration::zero().humanize(), "0s");
+        assert_eq!((-FloatDuration::zero()).humanize(), "0s");
+        assert_eq!(FloatDuration::microseconds(10.0).humanize(), "0s");
+
+        assert_eq!((-duration1).humanize(), "-1h 23m 45s");
+        assert_eq!(FloatDuration::minutes(-90.0).humanize(), "-1h 30m");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_humanize_components() {
+        let duration = FloatDuration::days(2.0) + FloatDuration::hours(3.0) +
+                       FloatDuration::minutes(4.0) + FloatDuration::seconds(5.0);
+
+        assert_eq!(duration.humanize_components(1), "2d");
+        assert_eq!(duration.humanize_components(2), "2d 3h");
+        assert_eq!(duration.humanize_components(4), "2d 3h 4m 5s");
+        assert_eq!(duration.humanize_components(10), "2d 3h 4m 5s");
+
+        let sparse = FloatDuration::days(1.0) + FloatDuration::minutes(5.0);
+        assert_eq!(sparse.humanize_components(2), "1d");
+        assert_eq!(sparse.humanize_components(3), "1d 5m");
+        assert_eq!((-sparse).humanize_components(3), "-1d 5m");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(FloatDuration::zero().format_clock(3), "000:00:00:00");
+        assert_eq!(FloatDuration::seconds(4.75).format_clock(3), "000:00:00:04");
+        assert_eq!(FloatDuration::minutes(90.0).format_clock(2), "00:01:30:00");
+        assert_eq!(FloatDuration::hours(23.0).format_clock(0), "0:23:00:00");
+
+        let duration = FloatDuration::days(12.0) + FloatDuration::hours(2.0) +
+                       FloatDuration::minutes(3.0) + FloatDuration::seconds(4.0);
+        assert_eq!(duration.format_clock(3), "012:02:03:04");
+        assert_eq!(FloatDuration::days(12345.0).format_clock(3), "12345:00:00:00");
+
+        assert_eq!((-duration).format_clock(3), "-012:02:03:04");
+        assert_eq!(FloatDuration::seconds(-0.5).format_clock(3), "000:00:00:00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_iso8601() {
+        assert_eq!(FloatDuration::minutes(90.0).to_iso8601().unwrap(), "PT1H30M");
+        assert_eq!(FloatDuration::hours(26.0).to_iso8601().unwrap(), "P1DT2H");
+        assert_eq!(FloatDuration::days(3.0).to_iso8601().unwrap(), "P3D");
+        assert_eq!(FloatDuration::seconds(1.5).to_iso8601().unwrap(), "PT1.5S");
+        assert_eq!(FloatDuration::seconds(61.1).to_iso8601().unwrap(), "PT1M1.1S");
+        assert_eq!(FloatDuration::milliseconds(250.0).to_iso8601().unwrap(), "PT0.25S");
+        assert_eq!(FloatDuration::nanoseconds(1.0).to_iso8601().unwrap(), "PT0.000000001S");
+        assert_eq!((FloatDuration::days(1.0) + FloatDuration::seconds(5.0)).to_iso8601().unwrap(),
+                   "P1DT5S");
+        assert_eq!(FloatDuration::zero().to_iso8601().unwrap(), "PT0S");
+        assert_eq!((-FloatDuration::zero()).to_iso8601().unwrap(), "PT0S");
+        assert_eq!(FloatDuration::minutes(-5.0).to_iso8601().unwrap(), "-PT5M");
+        assert_eq!(FloatDuration::hours(-26.5).to_iso8601().unwrap(), "-P1DT2H30M");
+
+        assert_eq!(FloatDuration::seconds(f64::NAN).to_iso8601(),
+                   Err(ConversionError::NotFinite));
+        assert_eq!(FloatDuration::seconds(f64::INFINITY).to_iso8601(),
+                   Err(ConversionError::NotFinite));
+        assert_eq!(FloatDuration::seconds(f64::NEG_INFINITY).to_iso8601(),
+                   Err(ConversionError::NotFinite));
+        assert_eq!(FloatDuration::seconds(1e20).to_iso8601(), Err(ConversionError::Overflow));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_iso8601() {
+        assert_eq!(FloatDuration::from_iso8601("PT1H30M").unwrap(),
+                   FloatDuration::minutes(90.0));
+        assert_eq!(FloatDuration::from_iso8601("P1DT2H").unwrap(),
+                   FloatDuration::hours(26.0));
+        assert_eq!(FloatDuration::from_iso8601("PT1.5S").unwrap(),
+                   FloatDuration::seconds(1.5));
+        assert_eq!(FloatDuration::from_iso8601("PT0,5S").unwrap(),
+                   FloatDuration::seconds(0.5));
+        assert_eq!(FloatDuration::from_iso8601("P2W").unwrap(),
+                   FloatDuration::weeks(2.0));
+        assert_eq!(FloatDuration::from_iso8601("P1Y").unwrap(),
+                   FloatDuration::years(1.0));
+        assert_eq!(FloatDuration::from_iso8601("P0.5D").unwrap(),
+                   FloatDuration::hours(12.0));
+        assert_eq!(FloatDuration::from_iso8601("PT0S").unwrap(), FloatDuration::zero());
+        assert_eq!(FloatDuration::from_iso8601("-PT5M").unwrap(),
+                   FloatDuration::minutes(-5.0));
+        assert_eq!(FloatDuration::from_iso8601("+PT5M").unwrap(),
+                   FloatDuration::minutes(5.0));
+
+        assert!(FloatDuration::from_iso8601("").is_err());
+        assert!(FloatDuration::from_iso8601("P").is_err());
+        assert!(FloatDuration::from_iso8601("PT").is_err());
+        assert!(FloatDuration::from_iso8601("P1DT").is_err());
+        assert!(FloatDuration::from_iso8601("1H").is_err());
+        assert!(FloatDuration::from_iso8601("P1M").is_err());
+        assert!(FloatDuration::from_iso8601("PT1M1H").is_err());
+        assert!(FloatDuration::from_iso8601("PTH").is_err());
+        assert!(FloatDuration::from_iso8601("PT5").is_err());
+        assert!(FloatDuration::from_iso8601("PT1.2.3S").is_err());
+        assert!(FloatDuration::from_iso8601("PT1S2S").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_iso8601_round_trip() {
+        let durations = [FloatDuration::minutes(90.0),
+                         FloatDuration::days(3.0) + FloatDuration::seconds(0.125),
+                         FloatDuration::seconds(59.75),
+                         FloatDuration::hours(-26.5),
+                         FloatDuration::days(400.0),
+                         FloatDuration::zero()];
+        for &duration in durations.iter() {
+            assert_eq!(FloatDuration::from_iso8601(&duration.to_iso8601().unwrap()).unwrap(),
+                       duration);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatter_default() {
+        let formatter = FloatDurationFormatter::new();
+        let durations = [FloatDuration::zero(),
+                         -FloatDuration::zero(),
+                         FloatDuration::seconds(1.5),
+                         FloatDuration::minutes(-3.0),
+                         FloatDuration::days(400.0),
+                         FloatDuration::microseconds(12.0),
+                         FloatDuration::seconds(1e-12)];
+        for &duration in durations.iter() {
+            assert_eq!(formatter.format(duration), duration.to_string());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatter_unit() {
+        let formatter = FloatDurationFormatter::new().unit(TimeUnit::Minutes);
+        assert_eq!(formatter.format(FloatDuration::hours(2.0)), "120 minutes");
+        assert_eq!(formatter.format(FloatDuration::seconds(30.0)), "0.5 minutes");
+        assert_eq!(formatter.format(FloatDuration::seconds(-90.0)), "-1.5 minutes");
+        assert_eq!(formatter.format(FloatDuration::zero()), "0 minutes");
+
+        let formatter = FloatDurationFormatter::new().unit(TimeUnit::Weeks);
+        assert_eq!(formatter.format(FloatDuration::days(14.0)), "2 weeks");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatter_short_units() {
+        let formatter = FloatDurationFormatter::new().short_units(true);
+        assert_eq!(formatter.format(FloatDuration::seconds(1.5)), "1.5s");
+        assert_eq!(formatter.format(FloatDuration::hours(3.0)), "3h");
+        assert_eq!(formatter.format(FloatDuration::milliseconds(20.0)), "20ms");
+        assert_eq!(formatter.format(FloatDuration::microseconds(5.0)), "5\u{b5}s");
+        assert_eq!(formatter.format(FloatDuration::zero()), "0s");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatter_precision() {
+        let formatter = FloatDurationFormatter::new().precision(2);
+        assert_eq!(formatter.format(FloatDuration::seconds(1.0)), "1.00 seconds");
+        assert_eq!(formatter.format(FloatDuration::minutes(1.0 / 3.0)), "20.00 seconds");
+        assert_eq!(formatter.format(FloatDuration::hours(2.0 / 3.0)), "40.00 minutes");
+        assert_eq!(formatter.format(FloatDuration::seconds(1.5e-12)), "1.50e-12 seconds");
+
+        let formatter = FloatDurationFormatter::new()
+            .unit(TimeUnit::Hours)
+            .precision(0)
+            .short_units(true);
+        assert_eq!(formatter.format(FloatDuration::minutes(100.0)), "2h");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatter_explicit_sign() {
+        let formatter = FloatDurationFormatter::new().explicit_sign(true);
+        assert_eq!(formatter.format(FloatDuration::seconds(2.0)), "+2 seconds");
+        assert_eq!(formatter.format(FloatDuration::seconds(-2.0)), "-2 seconds");
+        assert_eq!(formatter.format(FloatDuration::zero()), "+0 seconds");
+        assert_eq!(formatter.format(FloatDuration::seconds(f64::NAN)), "NaN seconds");
+    }
+
+    #[test]
+    fn test_time_unit_duration() {
+        assert_eq!(TimeUnit::Hours.duration(1.5), FloatDuration::minutes(90.0));
+        assert_eq!(TimeUnit::Milliseconds.duration(250.0), FloatDuration::seconds(0.25));
+        assert_eq!(TimeUnit::Days.convert(TimeUnit::Days.duration(2.25)), 2.25);
+        assert_eq!(TimeUnit::Nanoseconds.duration(0.0), FloatDuration::zero());
+    }
+
+    #[test]
+    fn test_time_unit_from_name() {
+        for &unit in TimeUnit::ALL.iter() {
+            assert_eq!(TimeUnit::from_name(unit.name()), Some(unit));
+        }
+        assert_eq!(TimeUnit::from_name("h"), None);
+        assert_eq!(TimeUnit::from_name("fortnights"), None);
+    }
+}
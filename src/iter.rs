@@ -1,7 +1,31 @@
 //! Utilities for iteration with duration objects.
+//!
+//! `FloatDuration` does not implement `std::iter::Step`, so ranges such as
+//! `FloatDuration::zero()..FloatDuration::seconds(3.0)` cannot be iterated. `Step`
+//! assumes a discrete type where every value has a unique successor, and
+//! `steps_between` must count the values between two endpoints exactly. For a
+//! floating-point quantity the only such successor is the next representable
+//! `f64`, whose distance depends on the magnitude of the value, so a range would
+//! visit an enormous and uneven number of points. Use [`step_by`](fn.step_by.html)
+//! to walk a range with an explicit step, or [`subdivide`](fn.subdivide.html) to
+//! visit a fixed number of evenly spaced points:
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use float_duration::FloatDuration;
+//! use float_duration::iter::step_by;
+//!
+//! let points: Vec<_> = step_by(FloatDuration::zero(),
+//!                              FloatDuration::seconds(3.0),
+//!                              FloatDuration::seconds(1.0))
+//!     .collect();
+//! assert_eq!(points.len(), 3);
+//! # }
+//! ```
 
 use duration::FloatDuration;
-use std::iter;
+use core::cmp;
+use core::iter;
 
 /// An iterator over an evenly spaced lattice of `FloatDuration`s.
 ///
@@ -21,13 +45,52 @@ impl Subdivide {
         let step_size = (end - start) / (steps - 1) as f64;
 
         Subdivide {
-            start: start,
-            step_size: step_size,
+            start,
+            step_size,
             len: steps,
             index: 0,
         }
     }
 
+    fn new_exclusive(start: FloatDuration, end: FloatDuration, steps: usize) -> Subdivide {
+        assert!(steps >= 1, "subdivide_exclusive requires at least one step");
+        let step_size = (end - start) / steps as f64;
+
+        Subdivide {
+            start,
+            step_size,
+            len: steps,
+            index: 0,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn with_step(start: FloatDuration, end: FloatDuration, step: FloatDuration) -> Subdivide {
+        assert!(!step.is_zero() && !step.is_nan(),
+                "step_by requires a non-zero step");
+        let span = end - start;
+        assert!(span.is_zero() || span.is_positive() == step.is_positive(),
+                "step_by requires a step in the direction of end");
+        let mut len = (span / step).ceil() as usize;
+        // The division may round up past a whole number of steps, which would
+        // make the last point land on (or beyond) the exclusive `end`.
+        while len > 0 {
+            let last = start + step * ((len - 1) as f64);
+            let past_end = if step.is_positive() { last >= end } else { last <= end };
+            if !past_end {
+                break;
+            }
+            len -= 1;
+        }
+
+        Subdivide {
+            start,
+            step_size: step,
+            len,
+            index: 0,
+        }
+    }
+
     /// The distance between steps in the iteration.
     pub fn step_size(&self) -> FloatDuration {
         self.step_size
@@ -53,6 +116,18 @@ impl Iterator for Subdivide {
         let left = self.len - self.index;
         (left, Some(left))
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<FloatDuration> {
+        // Points are computed directly from their index, so skipping is O(1).
+        if n >= self.len - self.index {
+            self.index = self.len;
+            None
+        } else {
+            self.index += n;
+            self.next()
+        }
+    }
 }
 
 impl DoubleEndedIterator for Subdivide {
@@ -65,10 +140,74 @@ impl DoubleEndedIterator for Subdivide {
             Some(self.start + self.step_size * (index as f64))
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<FloatDuration> {
+        if n >= self.len - self.index {
+            self.len = self.index;
+            None
+        } else {
+            self.len -= n;
+            self.next_back()
+        }
+    }
 }
 
 impl ExactSizeIterator for Subdivide {}
 
+/// An iterator over a reshaped lattice of `FloatDuration`s.
+///
+/// This type is returned by `subdivide_map`, and is not meant to be
+/// instantiated directly.
+#[derive(Debug, Clone)]
+pub struct SubdivideMap<F> {
+    params: Subdivide,
+    begin: FloatDuration,
+    span: FloatDuration,
+    f: F,
+}
+
+impl<F> SubdivideMap<F>
+    where F: Fn(f64) -> f64
+{
+    #[inline]
+    fn at(&self, param: FloatDuration) -> FloatDuration {
+        self.begin + self.span * (self.f)(param.as_seconds())
+    }
+}
+
+impl<F> Iterator for SubdivideMap<F>
+    where F: Fn(f64) -> f64
+{
+    type Item = FloatDuration;
+
+    #[inline]
+    fn next(&mut self) -> Option<FloatDuration> {
+        self.params.next().map(|t| self.at(t))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.params.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<FloatDuration> {
+        self.params.nth(n).map(|t| self.at(t))
+    }
+}
+
+impl<F> DoubleEndedIterator for SubdivideMap<F>
+    where F: Fn(f64) -> f64
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<FloatDuration> {
+        self.params.next_back().map(|t| self.at(t))
+    }
+}
+
+impl<F> ExactSizeIterator for SubdivideMap<F> where F: Fn(f64) -> f64 {}
+
 /// Subdivide the distance between two duration into `steps` evenly spaced points.
 ///
 /// `subdivide` returns an iterator that lazily computes and returns exactly `steps`
@@ -100,6 +239,61 @@ pub fn subdivide(begin: FloatDuration, end: FloatDuration, steps: usize) -> Subd
     Subdivide::new(begin, end, steps)
 }
 
+/// Subdivide the half-open interval `[begin, end)` into `steps` evenly spaced points.
+///
+/// `subdivide_exclusive` is similar to [`subdivide`](fn.subdivide.html), except that
+/// the step size is `(end - begin) / steps` and `end` itself is never returned. This
+/// makes it possible to concatenate adjacent ranges without visiting the shared
+/// boundary twice.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::subdivide_exclusive;
+///
+/// let points: Vec<_> = subdivide_exclusive(FloatDuration::zero(),
+///                                          FloatDuration::minutes(1.0),
+///                                          4)
+///     .collect();
+/// assert_eq!(points.last(), Some(&FloatDuration::seconds(45.0)));
+/// ```
+///
+/// # Panics
+/// This function panics if `steps < 1`.
+pub fn subdivide_exclusive(begin: FloatDuration, end: FloatDuration, steps: usize) -> Subdivide {
+    Subdivide::new_exclusive(begin, end, steps)
+}
+
+/// Walk from `begin` toward `end` in increments of `step`.
+///
+/// `step_by` returns an iterator over `begin`, `begin + step`, `begin + 2*step`, ...
+/// for as long as the values lie strictly before `end`. If `step` does not evenly
+/// divide the range, the final point is the last one before `end`; `end` itself is
+/// never returned. Each point is computed directly from `begin` so that rounding
+/// errors do not accumulate.
+///
+/// A negative `step` walks backward from `begin` to an earlier `end`. If `begin`
+/// equals `end`, the iterator is empty.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::step_by;
+///
+/// let points: Vec<_> = step_by(FloatDuration::zero(),
+///                              FloatDuration::seconds(10.0),
+///                              FloatDuration::seconds(4.0))
+///     .collect();
+/// assert_eq!(points, vec![FloatDuration::zero(),
+///                         FloatDuration::seconds(4.0),
+///                         FloatDuration::seconds(8.0)]);
+/// ```
+///
+/// # Panics
+/// This function panics if `step` is zero or NaN, or if it points away from `end`.
+#[cfg(feature = "std")]
+pub fn step_by(begin: FloatDuration, end: FloatDuration, step: FloatDuration) -> Subdivide {
+    Subdivide::with_step(begin, end, step)
+}
+
 /// Subdivide the distance between two duration into `steps` evenly spaced points
 /// and include a timestep.
 ///
@@ -158,9 +352,270 @@ pub fn subdivide_with_step(begin: FloatDuration,
     sub.zip(iter::repeat(step_size))
 }
 
+/// Integrate `f` over `[begin, end]` using the trapezoidal rule.
+///
+/// `f` is evaluated at the `steps` points returned by
+/// [`subdivide`](fn.subdivide.html), and the result is the approximate integral of
+/// `f` with respect to time in seconds. The rule is exact for functions that are
+/// linear in time. If `end` is before `begin`, the result is negated.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::integrate_trapezoidal;
+///
+/// // A constant speed of 3 m/s for 10 seconds covers 30 meters.
+/// let distance = integrate_trapezoidal(FloatDuration::zero(),
+///                                      FloatDuration::seconds(10.0),
+///                                      100,
+///                                      |_| 3.0);
+/// assert!((distance - 30.0).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+/// This function panics if `steps < 2`.
+pub fn integrate_trapezoidal<F>(begin: FloatDuration,
+                                end: FloatDuration,
+                                steps: usize,
+                                f: F)
+                                -> f64
+    where F: Fn(FloatDuration) -> f64
+{
+    let points = subdivide(begin, end, steps);
+    let step_size = points.step_size().as_seconds();
+    let sum: f64 = points.enumerate()
+        .map(|(i, t)| if i == 0 || i == steps - 1 { 0.5 * f(t) } else { f(t) })
+        .sum();
+    sum * step_size
+}
+
+/// Subdivide the distance between two durations into `steps` points spaced
+/// according to `f`.
+///
+/// The normalized parameter `t` visits `steps` evenly spaced values from `0` to
+/// `1`, as with [`subdivide`](fn.subdivide.html), and each point is
+/// `begin + (end - begin)*f(t)`. With the identity function, this visits the same
+/// points as `subdivide`; other functions allow logarithmic or eased sampling. If
+/// `f(0) = 0` and `f(1) = 1`, both endpoints are visited.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::subdivide_map;
+///
+/// // Sample more densely near the beginning.
+/// let points: Vec<_> = subdivide_map(FloatDuration::zero(),
+///                                    FloatDuration::seconds(8.0),
+///                                    3,
+///                                    |t| t * t)
+///     .collect();
+/// assert_eq!(points, vec![FloatDuration::zero(),
+///                         FloatDuration::seconds(2.0),
+///                         FloatDuration::seconds(8.0)]);
+/// ```
+///
+/// # Panics
+/// This function panics if `steps < 2`.
+pub fn subdivide_map<F>(begin: FloatDuration,
+                        end: FloatDuration,
+                        steps: usize,
+                        f: F)
+                        -> SubdivideMap<F>
+    where F: Fn(f64) -> f64
+{
+    SubdivideMap {
+        params: subdivide(FloatDuration::zero(), FloatDuration::seconds(1.0), steps),
+        begin,
+        span: end - begin,
+        f,
+    }
+}
+
+/// An iterator over the differences between adjacent durations.
+///
+/// This type is returned by `differences`, and is not meant to be instantiated
+/// directly.
+#[derive(Debug, Clone)]
+pub struct Differences<I> {
+    iter: I,
+    prev: Option<FloatDuration>,
+}
+
+impl<I> Iterator for Differences<I>
+    where I: Iterator<Item = FloatDuration>
+{
+    type Item = FloatDuration;
+
+    fn next(&mut self) -> Option<FloatDuration> {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+        self.prev = Some(next);
+        Some(next - prev)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.prev.is_some() {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|upper| upper.saturating_sub(1)))
+        }
+    }
+}
+
+/// Return an iterator over the differences `d_1 - d_0, d_2 - d_1, ...` between
+/// adjacent durations in a sequence.
+///
+/// This turns a series of cumulative timestamps into the lengths of the intervals
+/// between them. A sequence of `n` durations yields `n - 1` differences, which
+/// are negative wherever the sequence decreases.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::differences;
+///
+/// let laps = vec![FloatDuration::seconds(0.0),
+///                 FloatDuration::seconds(62.0),
+///                 FloatDuration::seconds(121.0)];
+/// let lap_times: Vec<_> = differences(laps).collect();
+/// assert_eq!(lap_times, vec![FloatDuration::seconds(62.0), FloatDuration::seconds(59.0)]);
+/// ```
+pub fn differences<I>(iter: I) -> Differences<I::IntoIter>
+    where I: IntoIterator<Item = FloatDuration>
+{
+    Differences {
+        iter: iter.into_iter(),
+        prev: None,
+    }
+}
+
+/// An iterator over the running totals of a sequence of durations.
+///
+/// This type is returned by `cumulative`, and is not meant to be instantiated
+/// directly.
+#[derive(Debug, Clone)]
+pub struct Cumulative<I> {
+    iter: I,
+    total: FloatDuration,
+}
+
+impl<I> Iterator for Cumulative<I>
+    where I: Iterator<Item = FloatDuration>
+{
+    type Item = FloatDuration;
+
+    #[inline]
+    fn next(&mut self) -> Option<FloatDuration> {
+        let next = self.iter.next()?;
+        self.total += next;
+        Some(self.total)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Return an iterator over the running totals `d_0, d_0 + d_1, ...` of a
+/// sequence of durations.
+///
+/// This is the inverse of [`differences`](fn.differences.html): it turns the
+/// lengths of successive intervals into timestamps measured from the start of
+/// the first interval.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::cumulative;
+///
+/// let lap_times = vec![FloatDuration::seconds(62.0), FloatDuration::seconds(59.0)];
+/// let splits: Vec<_> = cumulative(lap_times).collect();
+/// assert_eq!(splits, vec![FloatDuration::seconds(62.0), FloatDuration::seconds(121.0)]);
+/// ```
+pub fn cumulative<I>(iter: I) -> Cumulative<I::IntoIter>
+    where I: IntoIterator<Item = FloatDuration>
+{
+    Cumulative {
+        iter: iter.into_iter(),
+        total: FloatDuration::zero(),
+    }
+}
+
+/// Compute the weighted sum `w_0*d_0 + w_1*d_1 + ...` of a sequence of
+/// `(weight, duration)` pairs.
+///
+/// This is mainly useful for numerical integration over a non-uniform set of
+/// points. An empty sequence sums to zero.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::weighted_sum;
+///
+/// let total = weighted_sum(vec![(0.5, FloatDuration::seconds(4.0)),
+///                               (2.0, FloatDuration::seconds(1.5))]);
+/// assert_eq!(total, FloatDuration::seconds(5.0));
+/// ```
+pub fn weighted_sum<I>(iter: I) -> FloatDuration
+    where I: IntoIterator<Item = (f64, FloatDuration)>
+{
+    iter.into_iter().fold(FloatDuration::zero(), |acc, (w, d)| acc + d * w)
+}
+
+/// Return the shortest duration in a sequence, or `None` if it is empty.
+///
+/// Durations are compared with [`FloatDuration::total_cmp`], so the result is
+/// well defined even if the sequence contains NaN. Negative NaN is ordered
+/// before every other value, and positive NaN after every other value.
+///
+/// [`FloatDuration::total_cmp`]: ../duration/struct.FloatDuration.html#method.total_cmp
+pub fn min_duration<I>(iter: I) -> Option<FloatDuration>
+    where I: IntoIterator<Item = FloatDuration>
+{
+    iter.into_iter().min_by(|a, b| a.total_cmp(b))
+}
+
+/// Return the longest duration in a sequence, or `None` if it is empty.
+///
+/// As with [`min_duration`](fn.min_duration.html), durations are compared with
+/// `FloatDuration::total_cmp`.
+pub fn max_duration<I>(iter: I) -> Option<FloatDuration>
+    where I: IntoIterator<Item = FloatDuration>
+{
+    iter.into_iter().max_by(|a, b| a.total_cmp(b))
+}
+
+/// Return true if `durations` is sorted in non-decreasing order.
+///
+/// Adjacent durations are compared with `FloatDuration::total_cmp`, so the check
+/// is well defined for NaN: a positive NaN may only be followed by another, and a
+/// negative NaN may only appear before every other value. Note that `-0.0` is
+/// ordered before `0.0`. Empty and single-element slices are monotonic.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::iter::is_monotonic;
+///
+/// let knots = [FloatDuration::zero(), FloatDuration::seconds(1.0), FloatDuration::seconds(1.0)];
+/// assert!(is_monotonic(&knots));
+/// ```
+pub fn is_monotonic(durations: &[FloatDuration]) -> bool {
+    durations.windows(2).all(|pair| pair[0].total_cmp(&pair[1]) != cmp::Ordering::Greater)
+}
+
+/// Return true if `durations` is sorted in strictly increasing order.
+///
+/// As with [`is_monotonic`](fn.is_monotonic.html), durations are compared with
+/// `FloatDuration::total_cmp`.
+pub fn is_strictly_monotonic(durations: &[FloatDuration]) -> bool {
+    durations.windows(2).all(|pair| pair[0].total_cmp(&pair[1]) == cmp::Ordering::Less)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::vec;
+    use std::vec::Vec;
 
     #[test]
     fn test_subdivide() {
@@ -182,6 +637,282 @@ mod tests {
                         FloatDuration::zero()]);
     }
 
+    #[test]
+    fn test_subdivide_exclusive() {
+        let begin = FloatDuration::zero();
+        let end = FloatDuration::minutes(1.0);
+
+        let s = subdivide_exclusive(begin, end, 3);
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.step_size(), FloatDuration::seconds(20.0));
+        assert_eq!(s.clone().collect::<Vec<_>>(),
+                   subdivide(begin, end, 4).take(3).collect::<Vec<_>>());
+        assert_eq!(s.rev().collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(40.0),
+                        FloatDuration::seconds(20.0),
+                        FloatDuration::zero()]);
+
+        let joined = subdivide_exclusive(begin, end, 2)
+            .chain(subdivide_exclusive(end, end * 2.0, 2))
+            .collect::<Vec<_>>();
+        assert_eq!(joined,
+                   vec![FloatDuration::zero(),
+                        FloatDuration::seconds(30.0),
+                        FloatDuration::minutes(1.0),
+                        FloatDuration::seconds(90.0)]);
+
+        assert_eq!(subdivide_exclusive(begin, end, 1).collect::<Vec<_>>(),
+                   vec![FloatDuration::zero()]);
+    }
+
+    #[should_panic]
+    #[test]
+    fn test_subdivide_exclusive_panic() {
+        subdivide_exclusive(FloatDuration::zero(), FloatDuration::minutes(1.0), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_step_by() {
+        let mut s = step_by(FloatDuration::zero(),
+                            FloatDuration::minutes(10.0),
+                            FloatDuration::seconds(1.0));
+        assert_eq!(s.len(), 600);
+        assert_eq!(s.clone().next(), Some(FloatDuration::zero()));
+        assert_eq!(s.next_back(), Some(FloatDuration::seconds(599.0)));
+
+        assert_eq!(step_by(FloatDuration::zero(),
+                           FloatDuration::seconds(10.0),
+                           FloatDuration::seconds(4.0))
+                       .collect::<Vec<_>>(),
+                   vec![FloatDuration::zero(),
+                        FloatDuration::seconds(4.0),
+                        FloatDuration::seconds(8.0)]);
+        assert_eq!(step_by(FloatDuration::seconds(1.0),
+                           FloatDuration::seconds(3.0),
+                           FloatDuration::seconds(1.0))
+                       .collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(1.0), FloatDuration::seconds(2.0)]);
+        assert_eq!(step_by(FloatDuration::seconds(3.0),
+                           FloatDuration::zero(),
+                           FloatDuration::seconds(-1.5))
+                       .collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(3.0), FloatDuration::seconds(1.5)]);
+        assert_eq!(step_by(FloatDuration::seconds(3.0),
+                           FloatDuration::seconds(3.0),
+                           FloatDuration::seconds(1.0))
+                       .count(),
+                   0);
+
+        let end = FloatDuration::seconds(0.07);
+        let points = step_by(FloatDuration::zero(), end, FloatDuration::seconds(0.01))
+            .collect::<Vec<_>>();
+        assert_eq!(points.len(), 7);
+        assert!(points.iter().all(|&p| p < end));
+        let points = step_by(end, FloatDuration::zero(), FloatDuration::seconds(-0.01))
+            .collect::<Vec<_>>();
+        assert_eq!(points.len(), 7);
+        assert!(points.iter().all(|&p| p > FloatDuration::zero()));
+    }
+
+    #[cfg(feature = "std")]
+    #[should_panic]
+    #[test]
+    fn test_step_by_zero_panic() {
+        step_by(FloatDuration::zero(), FloatDuration::minutes(1.0), FloatDuration::zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[should_panic]
+    #[test]
+    fn test_step_by_direction_panic() {
+        step_by(FloatDuration::zero(),
+                FloatDuration::minutes(1.0),
+                FloatDuration::seconds(-1.0));
+    }
+
+    #[test]
+    fn test_subdivide_nth() {
+        let s = subdivide(FloatDuration::zero(), FloatDuration::hours(1.0), 1001);
+        let naive = s.clone().collect::<Vec<_>>();
+
+        for &k in [0, 1, 500, 999, 1000].iter() {
+            assert_eq!(s.clone().nth(k), Some(naive[k]));
+            assert_eq!(s.clone().nth_back(k), Some(naive[1000 - k]));
+        }
+        assert_eq!(s.clone().nth(1001), None);
+        assert_eq!(s.clone().nth_back(1001), None);
+
+        let mut s2 = s.clone();
+        assert_eq!(s2.nth(10), Some(naive[10]));
+        assert_eq!(s2.len(), 990);
+        assert_eq!(s2.size_hint(), (990, Some(990)));
+        assert_eq!(s2.nth_back(9), Some(naive[991]));
+        assert_eq!(s2.len(), 980);
+        assert_eq!(s2.next(), Some(naive[11]));
+        assert_eq!(s2.next_back(), Some(naive[990]));
+        assert_eq!(s2.nth(5000), None);
+        assert_eq!(s2.len(), 0);
+        assert_eq!(s2.next_back(), None);
+    }
+
+    #[test]
+    fn test_weighted_sum() {
+        assert_eq!(weighted_sum(iter::empty()), FloatDuration::zero());
+
+        let pairs = [(1.0, FloatDuration::minutes(1.0)),
+                     (0.25, FloatDuration::seconds(8.0)),
+                     (-2.0, FloatDuration::seconds(3.0))];
+        // 60 + 0.25*8 - 2*3 = 56
+        assert_eq!(weighted_sum(pairs.iter().cloned()), FloatDuration::seconds(56.0));
+
+        let weights = [0.5, 1.0, 1.0, 0.5];
+        let points = subdivide(FloatDuration::zero(), FloatDuration::seconds(3.0), 4);
+        assert_eq!(weighted_sum(weights.iter().cloned().zip(points)),
+                   FloatDuration::seconds(4.5));
+    }
+
+    #[test]
+    fn test_subdivide_map() {
+        let start = FloatDuration::zero();
+        let end = FloatDuration::seconds(4.0);
+
+        let identity: Vec<_> = subdivide_map(start, end, 5, |t| t).collect();
+        let linear: Vec<_> = subdivide(start, end, 5).collect();
+        assert_eq!(identity, linear);
+
+        let square = subdivide_map(start, end, 5, |t| t * t);
+        assert_eq!(square.len(), 5);
+        assert_eq!(square.collect::<Vec<_>>(),
+                   vec![FloatDuration::zero(),
+                        FloatDuration::seconds(0.25),
+                        FloatDuration::seconds(1.0),
+                        FloatDuration::seconds(2.25),
+                        FloatDuration::seconds(4.0)]);
+
+        let mut shifted = subdivide_map(FloatDuration::minutes(1.0),
+                                        FloatDuration::minutes(3.0),
+                                        3,
+                                        |t| t * t);
+        assert_eq!(shifted.next_back(), Some(FloatDuration::minutes(3.0)));
+        assert_eq!(shifted.nth(1), Some(FloatDuration::seconds(90.0)));
+        assert_eq!(shifted.next(), None);
+    }
+
+    #[test]
+    fn test_integrate_trapezoidal() {
+        let start = FloatDuration::zero();
+        let end = FloatDuration::seconds(4.0);
+
+        assert_eq!(integrate_trapezoidal(start, end, 2, |_| 3.0), 12.0);
+        assert_eq!(integrate_trapezoidal(start, end, 5, |t| 2.0 * t.as_seconds()), 16.0);
+        assert_eq!(integrate_trapezoidal(end, start, 5, |t| 2.0 * t.as_seconds()), -16.0);
+
+        // The integral of t^2 from 0 to 4 is 64/3.
+        let quadratic = integrate_trapezoidal(start, end, 1001, |t| t.as_seconds().powi(2));
+        assert!((quadratic - 64.0 / 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_min_max_duration() {
+        assert_eq!(min_duration(iter::empty()), None);
+        assert_eq!(max_duration(iter::empty()), None);
+
+        let single = [FloatDuration::seconds(3.0)];
+        assert_eq!(min_duration(single.iter().cloned()), Some(FloatDuration::seconds(3.0)));
+        assert_eq!(max_duration(single.iter().cloned()), Some(FloatDuration::seconds(3.0)));
+
+        let durations = [FloatDuration::minutes(1.0),
+                         FloatDuration::seconds(-90.0),
+                         FloatDuration::zero(),
+                         FloatDuration::hours(2.0),
+                         FloatDuration::seconds(-5.0)];
+        assert_eq!(min_duration(durations.iter().cloned()),
+                   Some(FloatDuration::seconds(-90.0)));
+        assert_eq!(max_duration(durations.iter().cloned()), Some(FloatDuration::hours(2.0)));
+
+        let with_nan = [FloatDuration::seconds(1.0), FloatDuration::seconds(f64::NAN)];
+        assert_eq!(min_duration(with_nan.iter().cloned()), Some(FloatDuration::seconds(1.0)));
+        assert!(max_duration(with_nan.iter().cloned()).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_differences() {
+        assert_eq!(differences(iter::empty()).next(), None);
+        assert_eq!(differences(iter::once(FloatDuration::seconds(1.0))).next(), None);
+
+        let series = [FloatDuration::seconds(1.0),
+                      FloatDuration::seconds(3.0),
+                      FloatDuration::seconds(3.5),
+                      FloatDuration::seconds(2.0),
+                      FloatDuration::minutes(1.0)];
+        let deltas = differences(series.iter().cloned());
+        assert_eq!(deltas.size_hint(), (4, Some(4)));
+        assert_eq!(deltas.collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(2.0),
+                        FloatDuration::seconds(0.5),
+                        FloatDuration::seconds(-1.5),
+                        FloatDuration::seconds(58.0)]);
+
+        let steps: Vec<_> = differences(subdivide(FloatDuration::zero(),
+                                                  FloatDuration::seconds(3.0),
+                                                  4))
+            .collect();
+        assert_eq!(steps, vec![FloatDuration::seconds(1.0); 3]);
+    }
+
+    #[test]
+    fn test_cumulative() {
+        assert_eq!(cumulative(iter::empty()).next(), None);
+
+        let intervals = [FloatDuration::seconds(2.0),
+                         FloatDuration::seconds(0.5),
+                         FloatDuration::seconds(-1.5),
+                         FloatDuration::minutes(1.0)];
+        assert_eq!(cumulative(intervals.iter().cloned()).collect::<Vec<_>>(),
+                   vec![FloatDuration::seconds(2.0),
+                        FloatDuration::seconds(2.5),
+                        FloatDuration::seconds(1.0),
+                        FloatDuration::seconds(61.0)]);
+
+        // Reconstruct a series relative to its first element.
+        let series = [FloatDuration::seconds(10.0),
+                      FloatDuration::seconds(12.0),
+                      FloatDuration::seconds(12.5),
+                      FloatDuration::seconds(11.0),
+                      FloatDuration::seconds(70.0)];
+        let first = series[0];
+        let rebuilt: Vec<_> = cumulative(differences(series.iter().cloned()))
+            .map(|offset| first + offset)
+            .collect();
+        assert_eq!(rebuilt, &series[1..]);
+    }
+
+    #[test]
+    fn test_is_monotonic() {
+        let one = FloatDuration::seconds(1.0);
+        let two = FloatDuration::seconds(2.0);
+        let nan = FloatDuration::seconds(f64::NAN);
+
+        assert!(is_monotonic(&[]));
+        assert!(is_strictly_monotonic(&[one]));
+
+        assert!(is_monotonic(&[FloatDuration::seconds(-1.0), one, two]));
+        assert!(is_strictly_monotonic(&[FloatDuration::seconds(-1.0), one, two]));
+
+        assert!(is_monotonic(&[one, one, two]));
+        assert!(!is_strictly_monotonic(&[one, one, two]));
+
+        assert!(!is_monotonic(&[one, two, one]));
+        assert!(!is_strictly_monotonic(&[two, one]));
+
+        assert!(is_monotonic(&[one, two, nan]));
+        assert!(!is_monotonic(&[one, nan, two]));
+        assert!(!is_strictly_monotonic(&[nan, one]));
+        assert!(is_monotonic(&[-nan, one]));
+        assert!(!is_monotonic(&[FloatDuration::zero(), -FloatDuration::zero()]));
+    }
+
     #[should_panic]
     #[test]
     fn test_subdivide_panic() {
@@ -108,6 +108,7 @@
 //! can be used directly with `SystemTime` and `Instant`:
 //!
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! // TimePoint needed for `float_duration_since`.
 //! use float_duration::{FloatDuration, TimePoint};
 //! use std::time::{Instant, SystemTime};
@@ -118,10 +119,23 @@
 //! let end_time = Instant::now();
 //!
 //! println!("Took {}.", end_time.float_duration_since(start_time).unwrap());
+//! # }
 //! ```
 //!
 //! `FloatDuration` may also be converted to/from `std::time::Duration` via the
-//! `to_std` and `from_std` methods.
+//! `to_std` and `from_std` methods, or added to and subtracted from one directly.
+//!
+//! ## no_std
+//! The `std` feature is enabled by default. Without it, `float_duration` is a
+//! `#![no_std]` crate: construction, accessors, comparisons and arithmetic of
+//! `FloatDuration` are still available, but everything that needs an allocator,
+//! the system clock, or floating-point rounding functions is left out. The
+//! `chrono`, `time`, `approx` and `serde` features all require `std`.
+//!
+//! ```toml
+//! [dependencies]
+//! float_duration = { version = "0.3", default-features = false }
+//! ```
 //!
 //! ## [approx](https://crates.io/crates/approx)
 //! `FloatDuration` provides an implementation of `approx::ApproxEq`
@@ -154,9 +168,32 @@
 //!
 //! ## [serde](https://crates.io/crates/serde)
 //!
-//! `FloatDuration` supports serialization with `serde`. Presently, a `FloatDuration`
-//! is serialized to a single `f64` value representing the number of seconds in the
-//! duration.
+//! `FloatDuration` supports serialization with `serde`. In human-readable formats such
+//! as JSON, a `FloatDuration` is serialized to a string in the unit that `Display`
+//! uses, e.g. `"1.5 hours"`. If that would not parse back to exactly the same value,
+//! the string holds its exact number of seconds instead, such as
+//! `"60.593826614899996 seconds"`, so the value always round-trips without loss.
+//! In compact formats, it is serialized to a single `f64` value representing the number
+//! of seconds in the duration. Deserialization accepts integer and floating-point
+//! numbers of seconds as well as any string that `FloatDuration` can be parsed from.
+//!
+//! To serialize a `FloatDuration` field as an object such as `{ "seconds": 90.0 }`
+//! instead, use `#[serde(with = "float_duration::serde_struct")]`. Similarly,
+//! `float_duration::serde_millis` and `float_duration::serde_days` represent it as
+//! a number of milliseconds or days respectively, and
+//! `float_duration::serde_tagged` as a value and a unit name, such as
+//! `{ "value": 1.5, "unit": "hours" }`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// `core` is only injected automatically in `no_std` builds.
+#[cfg(feature = "std")]
+extern crate core;
+
+// The test harness links `std` anyway, so tests may use `std` paths and macros even
+// when the library itself is built without it.
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
 
 #[cfg(feature = "chrono")]
 extern crate chrono;
@@ -168,12 +205,87 @@ extern crate approx;
 extern crate serde;
 #[cfg(all(test, feature = "serde"))]
 extern crate serde_test;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 
+// Declared before the `serde_*` modules, whose tests use its macros.
+#[cfg(all(test, feature = "serde"))]
+#[macro_use]
+mod serde_test_util;
 
+
+#[cfg(feature = "std")]
+pub mod decomposed;
 pub mod duration;
 pub mod error;
+pub mod format;
 pub mod iter;
+#[cfg(feature = "serde")]
+pub mod serde_days;
+#[cfg(feature = "serde")]
+pub mod serde_millis;
+#[cfg(feature = "serde")]
+pub mod serde_struct;
+#[cfg(feature = "serde")]
+pub mod serde_tagged;
+#[cfg(feature = "serde")]
+mod serde_unit;
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod stopwatch;
+
+#[cfg(feature = "std")]
+pub use decomposed::{DecomposedTime, DecomposedTimeLong};
+pub use duration::{FloatDuration, FloatDurationBuilder, FiniteFloatDuration, AllUnits, TimePoint,
+                   FromDuration, IntoDuration};
+#[cfg(feature = "std")]
+pub use duration::TimePointExt;
+pub use error::{ConversionError, OutOfRangeError, ParseDurationError};
+#[cfg(feature = "std")]
+pub use format::FloatDurationFormatter;
+pub use format::TimeUnit;
+pub use iter::{subdivide, subdivide_exclusive, subdivide_with_step, weighted_sum, min_duration,
+               max_duration, integrate_trapezoidal, subdivide_map, is_monotonic,
+               is_strictly_monotonic, differences, cumulative};
+#[cfg(feature = "std")]
+pub use iter::step_by;
+pub use stats::DurationAverage;
+#[cfg(feature = "std")]
+pub use stats::Histogram;
+#[cfg(feature = "std")]
+pub use stopwatch::{Countdown, Stopwatch};
+
+// Exercises the parts of the API that remain available without `std`, using only
+// `core`, so that it also runs under `cargo test --no-default-features`.
+#[cfg(test)]
+mod no_std_tests {
+    use core::cmp::Ordering;
+    use core::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_no_std_smoke() {
+        const TIMEOUT: FloatDuration = FloatDuration::seconds(30.0);
+
+        let duration = FloatDuration::minutes(1.5) - FloatDuration::seconds(10.0) - TIMEOUT / 1.5;
+        assert_eq!(duration, FloatDuration::minutes(1.0));
+        assert_eq!((duration * 2.0).as_minutes(), 2.0);
+        assert_eq!(duration / FloatDuration::seconds(15.0), 4.0);
+        assert_eq!(-duration, FloatDuration::seconds(-60.0));
+        assert!(duration > TIMEOUT);
+        assert!(FloatDuration::seconds(f64::NAN).partial_cmp(&duration).is_none());
+
+        assert_eq!(FloatDuration::from(Duration::from_millis(1500)),
+                   FloatDuration::seconds(1.5));
+
+        let total: FloatDuration = subdivide(FloatDuration::zero(), FloatDuration::seconds(4.0), 5)
+            .sum();
+        assert_eq!(total, FloatDuration::seconds(10.0));
 
-pub use duration::{FloatDuration, TimePoint, FromDuration, IntoDuration};
-pub use error::OutOfRangeError;
-pub use iter::{subdivide, subdivide_with_step};
+        let a = FiniteFloatDuration::new(FloatDuration::seconds(1.0)).unwrap();
+        let b = FiniteFloatDuration::new(FloatDuration::seconds(2.0)).unwrap();
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert!(FiniteFloatDuration::new(FloatDuration::seconds(f64::INFINITY)).is_none());
+    }
+}
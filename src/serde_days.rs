@@ -0,0 +1,79 @@
+//! Serialization of `FloatDuration` as a number of days.
+//!
+//! Astronomical and scheduling configurations often express durations as a
+//! fractional number of days. This module may be used with
+//! `#[serde(with = "...")]` to serialize a `FloatDuration` as the value of
+//! `as_days`, and to deserialize one from any integer or floating-point number
+//! of days.
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Observation {
+//!     #[serde(with = "float_duration::serde_days")]
+//!     period: FloatDuration,
+//! }
+//! ```
+use serde::{Deserializer, Serializer};
+
+use duration::FloatDuration;
+use format::TimeUnit;
+use serde_unit;
+
+/// Serialize a `FloatDuration` as an `f64` number of days.
+pub fn serialize<S>(duration: &FloatDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    serde_unit::serialize(duration, TimeUnit::Days, serializer)
+}
+
+/// Deserialize a `FloatDuration` from a number of days.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<FloatDuration, D::Error>
+    where D: Deserializer<'de>
+{
+    serde_unit::deserialize(TimeUnit::Days, deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use serde_test_util::assert_json_round_trip;
+    use serde_test::{Token, assert_tokens, assert_de_tokens, assert_de_tokens_error};
+
+    serde_with_wrapper!();
+
+    #[test]
+    fn test_tokens() {
+        assert_tokens(&Wrapper(FloatDuration::days(2.0)), &[Token::F64(2.0)]);
+        assert_tokens(&Wrapper(FloatDuration::hours(36.0)), &[Token::F64(1.5)]);
+        assert_tokens(&Wrapper(FloatDuration::zero()), &[Token::F64(0.0)]);
+
+        assert_de_tokens(&Wrapper(FloatDuration::weeks(1.0)), &[Token::U64(7)]);
+        assert_de_tokens(&Wrapper(FloatDuration::hours(-12.0)), &[Token::F64(-0.5)]);
+        assert_de_tokens(&Wrapper(FloatDuration::days(-3.0)), &[Token::I64(-3)]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_de_tokens_error::<Wrapper>(&[Token::Str("2 days")],
+                                          "invalid type: string \"2 days\", expected a \
+                                           numeric value of days");
+    }
+
+    #[test]
+    fn test_json() {
+        assert_json_round_trip(Wrapper,
+                               &[FloatDuration::days(365.0),
+                                 FloatDuration::days(2.25),
+                                 FloatDuration::hours(6.0),
+                                 FloatDuration::days(-1.5)]);
+
+        assert_eq!(serde_json::to_string(&Wrapper(FloatDuration::days(2.25))).unwrap(),
+                   "2.25");
+        assert_eq!(serde_json::from_str::<Wrapper>("29").unwrap(),
+                   Wrapper(FloatDuration::days(29.0)));
+        assert_eq!(serde_json::from_str::<Wrapper>("0.5").unwrap(),
+                   Wrapper(FloatDuration::hours(12.0)));
+        assert!(serde_json::from_str::<Wrapper>("\"29d\"").is_err());
+    }
+}
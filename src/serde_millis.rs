@@ -0,0 +1,71 @@
+//! Serialization of `FloatDuration` as a number of milliseconds.
+//!
+//! Many APIs express durations as a number of milliseconds. This module may be
+//! used with `#[serde(with = "...")]` to serialize a `FloatDuration` as the value
+//! of `as_milliseconds`, and to deserialize one from any integer or
+//! floating-point number of milliseconds.
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Request {
+//!     #[serde(with = "float_duration::serde_millis")]
+//!     timeout: FloatDuration,
+//! }
+//! ```
+use serde::{Deserializer, Serializer};
+
+use duration::FloatDuration;
+use format::TimeUnit;
+use serde_unit;
+
+/// Serialize a `FloatDuration` as an `f64` number of milliseconds.
+pub fn serialize<S>(duration: &FloatDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    serde_unit::serialize(duration, TimeUnit::Milliseconds, serializer)
+}
+
+/// Deserialize a `FloatDuration` from a number of milliseconds.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<FloatDuration, D::Error>
+    where D: Deserializer<'de>
+{
+    serde_unit::deserialize(TimeUnit::Milliseconds, deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use serde_test::{Token, assert_tokens, assert_de_tokens, assert_de_tokens_error};
+
+    serde_with_wrapper!();
+
+    #[test]
+    fn test_tokens() {
+        assert_tokens(&Wrapper(FloatDuration::seconds(1.5)), &[Token::F64(1500.0)]);
+        assert_tokens(&Wrapper(FloatDuration::milliseconds(0.25)), &[Token::F64(0.25)]);
+        assert_tokens(&Wrapper(FloatDuration::zero()), &[Token::F64(0.0)]);
+
+        assert_de_tokens(&Wrapper(FloatDuration::seconds(2.0)), &[Token::U64(2000)]);
+        assert_de_tokens(&Wrapper(FloatDuration::seconds(-0.5)), &[Token::I64(-500)]);
+        assert_de_tokens(&Wrapper(FloatDuration::milliseconds(20.0)), &[Token::U16(20)]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_de_tokens_error::<Wrapper>(&[Token::Str("1500")],
+                                          "invalid type: string \"1500\", expected a \
+                                           numeric value of milliseconds");
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(serde_json::to_string(&Wrapper(FloatDuration::seconds(1.5))).unwrap(),
+                   "1500.0");
+        assert_eq!(serde_json::from_str::<Wrapper>("1500").unwrap(),
+                   Wrapper(FloatDuration::seconds(1.5)));
+        assert_eq!(serde_json::from_str::<Wrapper>("12.5").unwrap(),
+                   Wrapper(FloatDuration::milliseconds(12.5)));
+        assert!(serde_json::from_str::<Wrapper>("\"1500ms\"").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Serialization of `FloatDuration` as a struct with a single `seconds` field.
+//!
+//! By default, a `FloatDuration` is serialized as a bare number or string. This
+//! module may be used with `#[serde(with = "...")]` to instead serialize it as a
+//! self-describing object, e.g. `{ "seconds": 90.0 }` in JSON.
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "float_duration::serde_struct")]
+//!     timeout: FloatDuration,
+//! }
+//! ```
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serializer};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+
+use duration::FloatDuration;
+
+const STRUCT_NAME: &str = "FloatDuration";
+const FIELDS: &[&str] = &["seconds"];
+
+/// Serialize a `FloatDuration` as a struct with a `seconds` field.
+pub fn serialize<S>(duration: &FloatDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    let mut state = serializer.serialize_struct(STRUCT_NAME, 1)?;
+    state.serialize_field("seconds", &duration.as_seconds())?;
+    state.end()
+}
+
+/// Deserialize a `FloatDuration` from a struct with a `seconds` field.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<FloatDuration, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_struct(STRUCT_NAME, FIELDS, StructVisitor)
+}
+
+/// The identifier of the only field, `seconds`.
+struct SecondsField;
+
+impl<'de> Deserialize<'de> for SecondsField {
+    fn deserialize<D>(deserializer: D) -> Result<SecondsField, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_identifier(SecondsFieldVisitor)
+    }
+}
+
+struct SecondsFieldVisitor;
+
+impl<'de> Visitor<'de> for SecondsFieldVisitor {
+    type Value = SecondsField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`seconds`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<SecondsField, E>
+        where E: de::Error
+    {
+        if value == "seconds" {
+            Ok(SecondsField)
+        } else {
+            Err(E::unknown_field(value, FIELDS))
+        }
+    }
+}
+
+struct StructVisitor;
+
+impl<'de> Visitor<'de> for StructVisitor {
+    type Value = FloatDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a struct with a `seconds` field")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<FloatDuration, A::Error>
+        where A: SeqAccess<'de>
+    {
+        let seconds = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        Ok(FloatDuration::seconds(seconds))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<FloatDuration, A::Error>
+        where A: MapAccess<'de>
+    {
+        let mut seconds = None;
+        while let Some(SecondsField) = map.next_key()? {
+            if seconds.is_some() {
+                return Err(de::Error::duplicate_field("seconds"));
+            }
+            seconds = Some(map.next_value()?);
+        }
+        let seconds = seconds.ok_or_else(|| de::Error::missing_field("seconds"))?;
+        Ok(FloatDuration::seconds(seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use serde_test::{Token, assert_tokens, assert_de_tokens, assert_de_tokens_error};
+
+    serde_with_wrapper!();
+
+    #[test]
+    fn test_tokens() {
+        assert_tokens(&Wrapper(FloatDuration::seconds(90.0)),
+                      &[Token::Struct {
+                            name: "FloatDuration",
+                            len: 1,
+                        },
+                        Token::Str("seconds"),
+                        Token::F64(90.0),
+                        Token::StructEnd]);
+        assert_de_tokens(&Wrapper(FloatDuration::minutes(-1.5)),
+                         &[Token::Seq { len: Some(1) }, Token::F64(-90.0), Token::SeqEnd]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_de_tokens_error::<Wrapper>(&[Token::Struct {
+                                                 name: "FloatDuration",
+                                                 len: 1,
+                                             },
+                                             Token::Str("minutes")],
+                                          "unknown field `minutes`, expected `seconds`");
+        assert_de_tokens_error::<Wrapper>(&[Token::Struct {
+                                                 name: "FloatDuration",
+                                                 len: 0,
+                                             },
+                                             Token::StructEnd],
+                                          "missing field `seconds`");
+    }
+
+    #[test]
+    fn test_json() {
+        let json = serde_json::to_string(&Wrapper(FloatDuration::seconds(90.0))).unwrap();
+        assert_eq!(json, "{\"seconds\":90.0}");
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(),
+                   Wrapper(FloatDuration::seconds(90.0)));
+        assert_eq!(serde_json::from_str::<Wrapper>("{\"seconds\": 2}").unwrap(),
+                   Wrapper(FloatDuration::seconds(2.0)));
+        assert!(serde_json::from_str::<Wrapper>("{\"seconds\": 1, \"seconds\": 2}").is_err());
+        assert!(serde_json::from_str::<Wrapper>("90.0").is_err());
+    }
+}
@@ -0,0 +1,259 @@
+//! Serialization of `FloatDuration` as a value tagged with its unit.
+//!
+//! This module may be used with `#[serde(with = "...")]` to serialize a
+//! `FloatDuration` as a struct with a numeric `value` and a `unit` name, e.g.
+//! `{ "value": 1.5, "unit": "hours" }` in JSON. Such a representation is easy
+//! to describe in a schema and to edit by hand.
+//!
+//! The unit is the same one `Display` would use: the largest unit of which the
+//! duration spans at least one, provided that the value in that unit converts
+//! back to exactly the same duration. Otherwise, as well as for zero and for
+//! durations smaller than a nanosecond, the value is written in seconds. Any of
+//! the unit names returned by `TimeUnit::name` is accepted when deserializing.
+//! Note that `serde_json` only parses every number exactly when its
+//! `float_roundtrip` feature is enabled.
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "float_duration::serde_tagged")]
+//!     timeout: FloatDuration,
+//! }
+//! ```
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serializer};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+
+use duration::FloatDuration;
+use format::TimeUnit;
+
+const STRUCT_NAME: &str = "FloatDuration";
+const FIELDS: &[&str] = &["value", "unit"];
+
+/// Serialize a `FloatDuration` as a struct with `value` and `unit` fields.
+pub fn serialize<S>(duration: &FloatDuration, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    let unit = match TimeUnit::display_unit(*duration) {
+        Some(unit) if unit.duration(unit.convert(*duration)) == *duration => unit,
+        _ => TimeUnit::Seconds,
+    };
+    let mut state = serializer.serialize_struct(STRUCT_NAME, 2)?;
+    state.serialize_field("value", &unit.convert(*duration))?;
+    state.serialize_field("unit", unit.name())?;
+    state.end()
+}
+
+/// Deserialize a `FloatDuration` from a struct with `value` and `unit` fields.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<FloatDuration, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_struct(STRUCT_NAME, FIELDS, TaggedVisitor)
+}
+
+/// Lists the accepted unit names in error messages.
+struct ExpectedUnitName;
+
+impl de::Expected for ExpectedUnitName {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("one of ")?;
+        for (i, unit) in TimeUnit::ALL.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "`{}`", unit.name())?;
+        }
+        Ok(())
+    }
+}
+
+/// A `TimeUnit` deserialized from its name.
+struct UnitName(TimeUnit);
+
+impl<'de> Deserialize<'de> for UnitName {
+    fn deserialize<D>(deserializer: D) -> Result<UnitName, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(UnitNameVisitor)
+    }
+}
+
+struct UnitNameVisitor;
+
+impl<'de> Visitor<'de> for UnitNameVisitor {
+    type Value = UnitName;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the name of a unit of time")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<UnitName, E>
+        where E: de::Error
+    {
+        TimeUnit::from_name(value)
+            .map(UnitName)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &ExpectedUnitName))
+    }
+}
+
+/// The identifiers of the two fields.
+enum Field {
+    Value,
+    Unit,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`value` or `unit`")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Field, E>
+        where E: de::Error
+    {
+        match value {
+            "value" => Ok(Field::Value),
+            "unit" => Ok(Field::Unit),
+            _ => Err(E::unknown_field(value, FIELDS)),
+        }
+    }
+}
+
+struct TaggedVisitor;
+
+impl<'de> Visitor<'de> for TaggedVisitor {
+    type Value = FloatDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a struct with `value` and `unit` fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<FloatDuration, A::Error>
+        where A: SeqAccess<'de>
+    {
+        let value = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let UnitName(unit) = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(unit.duration(value))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<FloatDuration, A::Error>
+        where A: MapAccess<'de>
+    {
+        let mut value = None;
+        let mut unit = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map.next_value()?);
+                }
+                Field::Unit => {
+                    if unit.is_some() {
+                        return Err(de::Error::duplicate_field("unit"));
+                    }
+                    let UnitName(name) = map.next_value()?;
+                    unit = Some(name);
+                }
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Ok(unit.duration(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use serde_test_util::{assert_json_round_trip, random_durations};
+    use serde_test::{Token, assert_tokens, assert_de_tokens_error};
+
+    serde_with_wrapper!();
+
+    #[test]
+    fn test_tokens() {
+        assert_tokens(&Wrapper(FloatDuration::hours(1.5)),
+                      &[Token::Struct {
+                            name: "FloatDuration",
+                            len: 2,
+                        },
+                        Token::Str("value"),
+                        Token::F64(1.5),
+                        Token::Str("unit"),
+                        Token::Str("hours"),
+                        Token::StructEnd]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_de_tokens_error::<Wrapper>(&[Token::Struct {
+                                                 name: "FloatDuration",
+                                                 len: 2,
+                                             },
+                                             Token::Str("value"),
+                                             Token::F64(2.0),
+                                             Token::Str("unit"),
+                                             Token::Str("fortnights")],
+                                          "invalid value: string \"fortnights\", expected \
+                                           one of `years`, `weeks`, `days`, `hours`, \
+                                           `minutes`, `seconds`, `milliseconds`, \
+                                           `microseconds`, `nanoseconds`");
+        assert_de_tokens_error::<Wrapper>(&[Token::Struct {
+                                                 name: "FloatDuration",
+                                                 len: 1,
+                                             },
+                                             Token::Str("value"),
+                                             Token::F64(2.0),
+                                             Token::StructEnd],
+                                          "missing field `unit`");
+    }
+
+    #[test]
+    fn test_json() {
+        assert_json_round_trip(Wrapper,
+                               &[FloatDuration::hours(1.5),
+                                 FloatDuration::minutes(-3.0),
+                                 FloatDuration::milliseconds(250.0),
+                                 FloatDuration::days(12.0),
+                                 FloatDuration::zero()]);
+
+        assert_eq!(serde_json::to_string(&Wrapper(FloatDuration::hours(1.5))).unwrap(),
+                   "{\"value\":1.5,\"unit\":\"hours\"}");
+        assert_eq!(serde_json::from_str::<Wrapper>("{\"unit\": \"weeks\", \"value\": 2}")
+                       .unwrap(),
+                   Wrapper(FloatDuration::weeks(2.0)));
+        assert!(serde_json::from_str::<Wrapper>("{\"value\": 2, \"unit\": \"h\"}").is_err());
+        assert!(serde_json::from_str::<Wrapper>("\"1.5 hours\"").is_err());
+    }
+
+    #[test]
+    fn test_json_random_round_trip() {
+        // JSON has no representation for infinity or NaN.
+        let durations: Vec<_> = random_durations(40_000)
+            .into_iter()
+            .filter(|d| d.is_finite())
+            .collect();
+        assert_json_round_trip(Wrapper, &durations);
+
+        assert_eq!(serde_json::to_string(&Wrapper(FloatDuration::seconds(60.593826614899996)))
+                       .unwrap(),
+                   "{\"value\":60.593826614899996,\"unit\":\"seconds\"}");
+    }
+}
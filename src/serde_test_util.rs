@@ -0,0 +1,66 @@
+//! Test helpers shared by the `serde_*` modules.
+use std::fmt::Debug;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use duration::FloatDuration;
+
+/// Define a `Wrapper(FloatDuration)` newtype whose `Serialize` and `Deserialize`
+/// impls call the `serialize` and `deserialize` functions in scope, as
+/// `#[serde(with = "...")]` would.
+macro_rules! serde_with_wrapper {
+    () => {
+        #[derive(Debug, PartialEq)]
+        struct Wrapper(FloatDuration);
+
+        impl ::serde::Serialize for Wrapper {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                serialize(&self.0, serializer)
+            }
+        }
+        impl<'de> ::serde::Deserialize<'de> for Wrapper {
+            fn deserialize<D>(deserializer: D) -> Result<Wrapper, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                deserialize(deserializer).map(Wrapper)
+            }
+        }
+    }
+}
+
+/// Generate `count` pseudo-random durations: half spread evenly over ±500000
+/// seconds and half from arbitrary `f64` bit patterns, including NaN and infinity.
+///
+/// A fixed xorshift seed is used, so every run checks the same values.
+pub fn random_durations(count: usize) -> Vec<FloatDuration> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..count)
+        .map(|i| if i % 2 == 0 {
+            FloatDuration::seconds((next() >> 11) as f64 / (1u64 << 53) as f64 * 1.0e6 - 5.0e5)
+        } else {
+            FloatDuration::seconds(f64::from_bits(next()))
+        })
+        .collect()
+}
+
+/// Assert that each of `durations`, wrapped with `wrap`, survives a JSON round trip.
+pub fn assert_json_round_trip<W, F>(wrap: F, durations: &[FloatDuration])
+    where W: Serialize + DeserializeOwned + PartialEq + Debug,
+          F: Fn(FloatDuration) -> W
+{
+    for &duration in durations.iter() {
+        let json = serde_json::to_string(&wrap(duration)).unwrap();
+        assert_eq!(serde_json::from_str::<W>(&json).unwrap(), wrap(duration), "{}", json);
+    }
+}
@@ -0,0 +1,51 @@
+//! Serialization of `FloatDuration` as a plain number of a fixed `TimeUnit`.
+//!
+//! This is the shared implementation behind `serde_millis` and `serde_days`.
+use std::fmt;
+
+use serde::{Deserializer, Serializer};
+use serde::de::{self, Visitor};
+
+use duration::FloatDuration;
+use format::TimeUnit;
+
+/// Serialize a `FloatDuration` as an `f64` number of `unit`.
+pub fn serialize<S>(duration: &FloatDuration, unit: TimeUnit, serializer: S)
+                    -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    serializer.serialize_f64(unit.convert(*duration))
+}
+
+/// Deserialize a `FloatDuration` from an integer or floating-point number of `unit`.
+pub fn deserialize<'de, D>(unit: TimeUnit, deserializer: D) -> Result<FloatDuration, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_f64(UnitVisitor(unit))
+}
+
+struct UnitVisitor(TimeUnit);
+
+impl<'de> Visitor<'de> for UnitVisitor {
+    type Value = FloatDuration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a numeric value of {}", self.0.name())
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(self.0.duration(value))
+    }
+    fn visit_i64<E>(self, value: i64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(self.0.duration(value as f64))
+    }
+    fn visit_u64<E>(self, value: u64) -> Result<FloatDuration, E>
+        where E: de::Error
+    {
+        Ok(self.0.duration(value as f64))
+    }
+}
@@ -0,0 +1,215 @@
+//! Summary statistics over sequences of durations.
+use duration::FloatDuration;
+
+/// An accumulator for the running average, minimum and maximum of a sequence of
+/// durations.
+///
+/// Samples are not stored; each statistic is updated incrementally as samples
+/// are pushed. The mean is updated with Welford's method, which avoids the loss
+/// of precision of keeping a large running sum.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::stats::DurationAverage;
+///
+/// let mut frame_times = DurationAverage::new();
+/// frame_times.push(FloatDuration::milliseconds(16.0));
+/// frame_times.push(FloatDuration::milliseconds(18.0));
+///
+/// assert_eq!(frame_times.average(), FloatDuration::milliseconds(17.0));
+/// assert_eq!(frame_times.max(), Some(FloatDuration::milliseconds(18.0)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DurationAverage {
+    count: u64,
+    mean: FloatDuration,
+    min: Option<FloatDuration>,
+    max: Option<FloatDuration>,
+}
+
+impl DurationAverage {
+    /// Create a new, empty `DurationAverage`.
+    pub fn new() -> DurationAverage {
+        DurationAverage::default()
+    }
+
+    /// Add a sample to the accumulator.
+    pub fn push(&mut self, duration: FloatDuration) {
+        self.count += 1;
+        self.mean += (duration - self.mean) / self.count as f64;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+
+    /// Return the mean of all samples pushed so far, or zero if there are none.
+    #[inline]
+    pub fn average(&self) -> FloatDuration {
+        self.mean
+    }
+    /// Return the smallest sample pushed so far, or `None` if there are none.
+    #[inline]
+    pub fn min(&self) -> Option<FloatDuration> {
+        self.min
+    }
+    /// Return the largest sample pushed so far, or `None` if there are none.
+    #[inline]
+    pub fn max(&self) -> Option<FloatDuration> {
+        self.max
+    }
+    /// Return the number of samples pushed so far.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// A histogram counting durations in buckets with fixed boundaries.
+///
+/// The boundaries `b_0 < b_1 < ... < b_n` define `n + 2` buckets: an underflow
+/// bucket for samples below `b_0`, a bucket `[b_(i-1), b_i)` between each pair of
+/// adjacent boundaries, and an overflow bucket for samples at or above `b_n`.
+/// Logarithmically spaced boundaries are convenient for latencies.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::stats::Histogram;
+///
+/// let mut latencies = Histogram::new(vec![FloatDuration::milliseconds(1.0),
+///                                         FloatDuration::milliseconds(10.0),
+///                                         FloatDuration::milliseconds(100.0)]);
+/// latencies.record(FloatDuration::milliseconds(4.0));
+/// latencies.record(FloatDuration::milliseconds(250.0));
+///
+/// assert_eq!(latencies.counts(), &[0, 1, 0, 1]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    boundaries: Vec<FloatDuration>,
+    counts: Vec<usize>,
+}
+
+#[cfg(feature = "std")]
+impl Histogram {
+    /// Create a new, empty `Histogram` with the given bucket boundaries.
+    ///
+    /// # Panics
+    /// This function panics if the boundaries are not finite and strictly
+    /// increasing.
+    pub fn new(boundaries: Vec<FloatDuration>) -> Histogram {
+        assert!(boundaries.iter().all(|b| b.is_finite()),
+                "histogram boundaries must be finite");
+        assert!(boundaries.windows(2).all(|pair| pair[0] < pair[1]),
+                "histogram boundaries must be strictly increasing");
+        let counts = vec![0; boundaries.len() + 1];
+
+        Histogram {
+            boundaries,
+            counts,
+        }
+    }
+
+    /// Count a sample in the bucket that contains it.
+    ///
+    /// NaN samples belong to no bucket and are ignored.
+    pub fn record(&mut self, duration: FloatDuration) {
+        if duration.is_nan() {
+            return;
+        }
+        let index = self.boundaries.partition_point(|&boundary| boundary <= duration);
+        self.counts[index] += 1;
+    }
+
+    /// Return the number of samples in each bucket, starting with the underflow
+    /// bucket and ending with the overflow bucket.
+    #[inline]
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+    /// Return the bucket boundaries.
+    #[inline]
+    pub fn boundaries(&self) -> &[FloatDuration] {
+        &self.boundaries
+    }
+    /// Return the total number of samples recorded.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let average = DurationAverage::new();
+        assert_eq!(average.count(), 0);
+        assert_eq!(average.average(), FloatDuration::zero());
+        assert_eq!(average.min(), None);
+        assert_eq!(average.max(), None);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut average = DurationAverage::new();
+        for &secs in [4.0, -2.0, 10.0, 8.0].iter() {
+            average.push(FloatDuration::seconds(secs));
+        }
+
+        assert_eq!(average.count(), 4);
+        assert_eq!(average.average(), FloatDuration::seconds(5.0));
+        assert_eq!(average.min(), Some(FloatDuration::seconds(-2.0)));
+        assert_eq!(average.max(), Some(FloatDuration::seconds(10.0)));
+
+        let mut single = DurationAverage::new();
+        single.push(FloatDuration::minutes(1.0));
+        assert_eq!(single.average(), FloatDuration::minutes(1.0));
+        assert_eq!(single.min(), single.max());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_histogram() {
+        let boundaries = vec![FloatDuration::milliseconds(1.0),
+                              FloatDuration::milliseconds(10.0),
+                              FloatDuration::milliseconds(100.0)];
+        let mut histogram = Histogram::new(boundaries.clone());
+        assert_eq!(histogram.counts(), &[0, 0, 0, 0]);
+        assert_eq!(histogram.boundaries(), &boundaries[..]);
+
+        let samples = [0.5, 1.0, 2.0, 9.5, 10.0, 50.0, 99.0, 100.0, 1000.0, -3.0];
+        for &millis in samples.iter() {
+            histogram.record(FloatDuration::milliseconds(millis));
+        }
+        histogram.record(FloatDuration::seconds(f64::NAN));
+        histogram.record(FloatDuration::seconds(f64::INFINITY));
+
+        assert_eq!(histogram.counts(), &[2, 3, 3, 3]);
+        assert_eq!(histogram.total(), 11);
+
+        let mut unbounded = Histogram::new(vec![]);
+        unbounded.record(FloatDuration::hours(1.0));
+        assert_eq!(unbounded.counts(), &[1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[should_panic]
+    #[test]
+    fn test_histogram_unsorted_panic() {
+        Histogram::new(vec![FloatDuration::seconds(2.0), FloatDuration::seconds(1.0)]);
+    }
+
+    #[test]
+    fn test_many_samples() {
+        let mut average = DurationAverage::new();
+        for _ in 0..1_000_000 {
+            average.push(FloatDuration::milliseconds(16.0));
+            average.push(FloatDuration::milliseconds(17.0));
+        }
+        assert_eq!(average.count(), 2_000_000);
+        assert!((average.average() - FloatDuration::milliseconds(16.5)).abs() <
+                FloatDuration::nanoseconds(1.0));
+    }
+}
@@ -0,0 +1,185 @@
+//! Measurement of elapsed wall-clock time.
+use std::time::Instant;
+
+use duration::{FloatDuration, TimePoint};
+
+/// A stopwatch measuring the time elapsed since it was started.
+///
+/// `Stopwatch` wraps a `std::time::Instant` and reports elapsed time as a
+/// `FloatDuration`, saving the boilerplate of calling `Instant::now()` and
+/// `float_duration_since` by hand.
+///
+/// ```rust
+/// use float_duration::stopwatch::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::start();
+/// // Do the first part of the work...
+/// let first = stopwatch.lap();
+/// // Do the second part of the work...
+/// let second = stopwatch.lap();
+///
+/// assert!(stopwatch.elapsed() >= first + second);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    start: Instant,
+    last_lap: Instant,
+}
+
+impl Stopwatch {
+    /// Create a new `Stopwatch` that starts timing immediately.
+    pub fn start() -> Stopwatch {
+        let now = Instant::now();
+        Stopwatch {
+            start: now,
+            last_lap: now,
+        }
+    }
+
+    /// Return the time elapsed since the stopwatch was started or last restarted.
+    pub fn elapsed(&self) -> FloatDuration {
+        elapsed_since(self.start, Instant::now())
+    }
+
+    /// Return the time elapsed since the stopwatch was started or last restarted,
+    /// and restart it.
+    pub fn restart(&mut self) -> FloatDuration {
+        let now = Instant::now();
+        let elapsed = elapsed_since(self.start, now);
+        self.start = now;
+        self.last_lap = now;
+        elapsed
+    }
+
+    /// Return the time elapsed since the previous lap, and begin a new lap.
+    ///
+    /// The first lap begins when the stopwatch is started or restarted. Taking a
+    /// lap does not affect the value returned by `elapsed`.
+    pub fn lap(&mut self) -> FloatDuration {
+        let now = Instant::now();
+        let lap = elapsed_since(self.last_lap, now);
+        self.last_lap = now;
+        lap
+    }
+}
+
+/// A countdown that expires once a fixed time budget has elapsed.
+///
+/// ```rust
+/// use float_duration::FloatDuration;
+/// use float_duration::stopwatch::Countdown;
+///
+/// let countdown = Countdown::start(FloatDuration::seconds(30.0));
+/// assert!(!countdown.is_expired());
+/// assert!(countdown.remaining() <= FloatDuration::seconds(30.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Countdown {
+    start: Instant,
+    budget: FloatDuration,
+}
+
+impl Countdown {
+    /// Create a new `Countdown` of length `budget` that began at `start`.
+    pub fn new(budget: FloatDuration, start: Instant) -> Countdown {
+        Countdown { start, budget }
+    }
+    /// Create a new `Countdown` of length `budget` that starts immediately.
+    pub fn start(budget: FloatDuration) -> Countdown {
+        Countdown::new(budget, Instant::now())
+    }
+
+    /// Return the total time budget of the countdown.
+    #[inline]
+    pub fn budget(&self) -> FloatDuration {
+        self.budget
+    }
+    /// Return the time remaining before the countdown expires.
+    ///
+    /// Once the countdown has expired, the result is negative and gives how far
+    /// past expiry it is.
+    pub fn remaining(&self) -> FloatDuration {
+        self.budget - elapsed_since(self.start, Instant::now())
+    }
+    /// Return true if the full budget has elapsed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining() <= FloatDuration::zero()
+    }
+}
+
+fn elapsed_since(since: Instant, now: Instant) -> FloatDuration {
+    now.float_duration_since(since).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_elapsed() {
+        let stopwatch = Stopwatch::start();
+        thread::sleep(Duration::from_millis(5));
+
+        let elapsed1 = stopwatch.elapsed();
+        assert!(elapsed1 >= FloatDuration::milliseconds(5.0));
+        thread::sleep(Duration::from_millis(1));
+        assert!(stopwatch.elapsed() > elapsed1);
+    }
+
+    #[test]
+    fn test_restart() {
+        let mut stopwatch = Stopwatch::start();
+        thread::sleep(Duration::from_millis(10));
+
+        let total = stopwatch.restart();
+        assert!(total >= FloatDuration::milliseconds(10.0));
+        assert!(stopwatch.elapsed() < total);
+    }
+
+    #[test]
+    fn test_lap() {
+        let mut stopwatch = Stopwatch::start();
+        thread::sleep(Duration::from_millis(5));
+        let lap1 = stopwatch.lap();
+        thread::sleep(Duration::from_millis(5));
+        let lap2 = stopwatch.lap();
+
+        assert!(lap1 >= FloatDuration::milliseconds(5.0));
+        assert!(lap2 >= FloatDuration::milliseconds(5.0));
+        assert!(stopwatch.elapsed() >= lap1 + lap2);
+    }
+
+    #[test]
+    fn test_countdown() {
+        // A generous budget, so that the countdown cannot expire on a slow machine.
+        let countdown = Countdown::start(FloatDuration::seconds(60.0));
+        assert_eq!(countdown.budget(), FloatDuration::seconds(60.0));
+        assert!(!countdown.is_expired());
+        assert!(countdown.remaining() <= FloatDuration::seconds(60.0));
+
+        let countdown = Countdown::start(FloatDuration::milliseconds(10.0));
+        thread::sleep(Duration::from_millis(15));
+        assert!(countdown.is_expired());
+        assert!(countdown.remaining() <= FloatDuration::milliseconds(-5.0));
+    }
+
+    #[test]
+    fn test_countdown_from_instant() {
+        let start = match Instant::now().checked_sub(Duration::from_secs(5)) {
+            Some(start) => start,
+            // The monotonic clock may not reach that far back on a freshly booted host.
+            None => return,
+        };
+        let countdown = Countdown::new(FloatDuration::seconds(2.0), start);
+        assert!(countdown.is_expired());
+        assert!(countdown.remaining() <= FloatDuration::seconds(-3.0));
+
+        let countdown = Countdown::new(FloatDuration::seconds(60.0), start);
+        assert!(!countdown.is_expired());
+        assert!(countdown.remaining() <= FloatDuration::seconds(55.0));
+
+        assert!(Countdown::start(FloatDuration::zero()).is_expired());
+    }
+}